@@ -0,0 +1,47 @@
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Errors returned by the detection entry points
+#[derive(Debug, Error)]
+pub enum DetectionError {
+    /// Filesystem access failed
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// A config file exists but could not be read (e.g. it is a directory)
+    #[error("config file {path:?} could not be read: {source}")]
+    UnreadableConfig {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    /// A file was read but its contents could not be parsed
+    #[error("failed to parse {path:?}: {source}")]
+    ParseError {
+        path: PathBuf,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    /// The path to scan or validate does not exist
+    #[error("path does not exist: {0:?}")]
+    PathNotFound(PathBuf),
+    /// A configuration environment variable holds an unusable value
+    #[error("invalid {key}={value:?}: {reason}")]
+    InvalidEnvVar {
+        key: String,
+        value: String,
+        reason: String,
+    },
+    /// Any other failure, such as an invalid pattern
+    #[error(transparent)]
+    Other(Box<dyn std::error::Error + Send + Sync>),
+}
+
+/// Result type of the detection entry points
+pub type DetectionResult<T> = std::result::Result<T, DetectionError>;
+
+#[cfg(feature = "tokio")]
+impl From<tokio::task::JoinError> for DetectionError {
+    fn from(err: tokio::task::JoinError) -> Self {
+        DetectionError::Other(Box::new(err))
+    }
+}
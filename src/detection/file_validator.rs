@@ -1,8 +1,14 @@
 use std::path::{Path, PathBuf};
-use std::fs;
+use std::collections::HashMap;
+use std::io::BufRead;
 use anyhow::{Result, Context};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use serde::{Deserialize, Serialize};
 
+use crate::detection::error::{DetectionError, DetectionResult};
+use crate::detection::matching;
+use crate::detection::vfs;
+
 /// File validation result with size and type information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileValidationResult {
@@ -13,8 +19,19 @@ pub struct FileValidationResult {
     pub reason: Option<String>,
 }
 
-/// Supported file types for ADK development
+/// File type, size and link status gathered from a single metadata lookup
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileInspection {
+    pub file_type: FileType,
+    /// Size in bytes (of the link target for symlinks)
+    pub size: u64,
+    /// Whether the path is a regular file (after following a symlink)
+    pub is_file: bool,
+    pub is_symlink: bool,
+}
+
+/// Supported file types for ADK development
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum FileType {
     /// Rust source files
     Rust,
@@ -32,7 +49,23 @@ pub enum FileType {
     Unknown,
 }
 
+/// Role a file plays within a project, independent of its language
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FilePurpose {
+    /// Hand-written application or library code
+    Source,
+    /// Test code (unit, integration, fixtures)
+    Test,
+    /// Configuration, environment and build files
+    Config,
+    /// Documentation files
+    Documentation,
+    /// Machine-generated files that should not be edited by hand
+    Generated,
+}
+
 /// File validator for ADK projects with size and type constraints
+#[derive(Debug, Clone)]
 pub struct FileValidator {
     /// Maximum file size in bytes
     max_file_size: u64,
@@ -42,11 +75,24 @@ pub struct FileValidator {
     allowed_extensions: Vec<String>,
     /// Excluded file patterns
     excluded_patterns: Vec<String>,
+    /// Maximum length of any single line in bytes (guards against minified content)
+    max_line_length: Option<usize>,
+    /// Maximum cumulative size of valid files accepted by one `validate_files` batch
+    max_total_bytes: Option<u64>,
+    /// Rules of the loaded `.gitignore`/`.adkignore` files, as written
+    ignore_rules: Vec<String>,
+    /// Compiled `.gitignore`/`.adkignore` matchers, parent directories before their
+    /// subdirectories
+    gitignores: Vec<Gitignore>,
+    /// Fail validation of JSON, YAML and TOML files that cannot be read or parsed
+    strict_parsing: bool,
+    /// Exclusion patterns and ignore-file rules, merged whenever either is added
+    exclusions: Vec<String>,
 }
 
 impl Default for FileValidator {
     fn default() -> Self {
-        Self {
+        let validator = Self {
             max_file_size: 50 * 1024 * 1024, // 50MB
             min_file_size: 1, // At least 1 byte
             allowed_extensions: vec![
@@ -75,6 +121,7 @@ impl Default for FileValidator {
                 "node_modules/**".to_string(),
                 ".venv/**".to_string(),
                 "__pycache__/**".to_string(),
+                "vendor/**".to_string(),
                 // Version control
                 ".git/**".to_string(),
                 ".svn/**".to_string(),
@@ -87,7 +134,14 @@ impl Default for FileValidator {
                 "*.log".to_string(),
                 "*.bak".to_string(),
             ],
-        }
+            max_line_length: None,
+            max_total_bytes: None,
+            ignore_rules: Vec::new(),
+            gitignores: Vec::new(),
+            strict_parsing: false,
+            exclusions: Vec::new(),
+        };
+        validator.with_merged_exclusions()
     }
 }
 
@@ -101,6 +155,21 @@ impl FileValidator {
         }
     }
 
+    /// Create a validator using the size limits of a [`DetectionConfig`], also excluding its
+    /// skip directories
+    ///
+    /// [`DetectionConfig`]: crate::DetectionConfig
+    pub fn from_config(config: &crate::DetectionConfig) -> Self {
+        let mut validator = Self::new(config.max_file_size, config.min_file_size);
+        for dir in &config.skip_dirs {
+            let pattern = format!("{}/**", dir);
+            if !validator.excluded_patterns.contains(&pattern) {
+                validator.excluded_patterns.push(pattern);
+            }
+        }
+        validator.with_merged_exclusions()
+    }
+
     /// Create a validator optimized for code review (smaller files)
     pub fn for_code_review() -> Self {
         Self {
@@ -126,24 +195,264 @@ impl FileValidator {
         }
     }
 
+    /// Make [`Self::validate_file`] read JSON, YAML and TOML files that pass the other checks
+    /// and fail with [`DetectionError::UnreadableConfig`] or [`DetectionError::ParseError`]
+    /// when one cannot be read or parsed
+    pub fn with_strict_parsing(mut self, enabled: bool) -> Self {
+        self.strict_parsing = enabled;
+        self
+    }
+
+    /// Reject files containing any line longer than `max_line_length` bytes
+    pub fn with_max_line_length(mut self, max_line_length: usize) -> Self {
+        self.max_line_length = Some(max_line_length);
+        self
+    }
+
+    /// Cap the cumulative size of valid files in a `validate_files` batch. Once a file would
+    /// push the total past `max_total_bytes`, it and every later file are rejected.
+    pub fn with_max_total_bytes(mut self, max_total_bytes: u64) -> Self {
+        self.max_total_bytes = Some(max_total_bytes);
+        self
+    }
+
+    /// Replace the allowed file extensions (a leading `.` is ignored)
+    pub fn with_allowed_extensions(mut self, extensions: &[&str]) -> Self {
+        self.allowed_extensions = extensions
+            .iter()
+            .map(|ext| ext.trim_start_matches('.').to_lowercase())
+            .collect();
+        self
+    }
+
+    /// Add a glob pattern for files to exclude
+    pub fn with_excluded_pattern(mut self, pattern: &str) -> Self {
+        self.excluded_patterns.push(pattern.to_string());
+        self.with_merged_exclusions()
+    }
+
+    /// Also exclude what the `.gitignore` and `.adkignore` files in `root` ignore, with full
+    /// gitignore semantics; `.adkignore` entries take precedence. Nested ignore files are not
+    /// read, see [`Self::with_gitignore`] for that. Paths are matched as given, so validate
+    /// them under `root` as it was passed here.
+    pub fn with_ignore_files<P: AsRef<Path>>(mut self, root: P) -> Self {
+        let ignore_files = self.read_ignore_files(root.as_ref(), &[".gitignore", ".adkignore"]);
+        if let Some(gitignore) = ignore_files {
+            self.add_gitignore(gitignore);
+        }
+        self.with_merged_exclusions()
+    }
+
+    /// Treat files ignored by the `.gitignore` files under `root` (including nested ones) as
+    /// excluded, with full gitignore semantics such as negation and anchoring. Paths are
+    /// matched as given, so validate them under `root` as it was passed here.
+    pub fn with_gitignore(mut self, root: &Path) -> Self {
+        // Parents come before children, and directories a parent ignores are not entered
+        let mut pending = vec![root.to_path_buf()];
+        while let Some(dir) = pending.pop() {
+            if let Some(gitignore) = self.read_ignore_files(&dir, &[".gitignore"]) {
+                self.add_gitignore(gitignore);
+            }
+            let Ok(entries) = vfs::read_dir(&dir) else {
+                continue;
+            };
+            let mut subdirs: Vec<PathBuf> = entries
+                .flatten()
+                .filter(|entry| entry.file_name() != ".git")
+                .filter(|entry| entry.metadata().is_ok_and(|metadata| metadata.is_dir()))
+                .map(|entry| entry.path())
+                .filter(|subdir| self.ignore_source(subdir, true).is_none())
+                .collect();
+            subdirs.sort();
+            pending.extend(subdirs.into_iter().rev());
+        }
+        self.with_merged_exclusions()
+    }
+
+    /// Load only the `.gitignore` files that can decide whether `path` is ignored: those in
+    /// `root` and the directories between it and `path`, stopping at an ignored directory
+    pub(crate) fn with_gitignores_above(mut self, root: &Path, path: &Path) -> Self {
+        let Some(relative) = path.strip_prefix(root).ok().and_then(Path::parent) else {
+            return self.with_merged_exclusions();
+        };
+        let mut dir = root.to_path_buf();
+        let mut components = relative.components();
+        loop {
+            if let Some(gitignore) = self.read_ignore_files(&dir, &[".gitignore"]) {
+                self.add_gitignore(gitignore);
+            }
+            let Some(component) = components.next() else {
+                break;
+            };
+            dir.push(component);
+            if self.ignore_source(&dir, true).is_some() {
+                break;
+            }
+        }
+        self.with_merged_exclusions()
+    }
+
+    /// Compile the ignore files `names` in `dir` into one matcher (later files take
+    /// precedence) and record their rules; `None` if none of them can be read. Unparseable
+    /// lines are skipped and the rest of the file still applies.
+    fn read_ignore_files(&mut self, dir: &Path, names: &[&str]) -> Option<Gitignore> {
+        let mut builder = GitignoreBuilder::new(dir);
+        let mut found = false;
+        for name in names {
+            let path = dir.join(name);
+            let Ok(content) = vfs::read_to_string(&path) else {
+                continue;
+            };
+            found = true;
+            for line in content.trim_start_matches('\u{feff}').lines() {
+                if builder.add_line(Some(path.clone()), line).is_err() {
+                    continue;
+                }
+                let rule = line.trim();
+                if !rule.is_empty() && !rule.starts_with('#') {
+                    self.ignore_rules.push(rule.to_string());
+                }
+            }
+        }
+        found.then(|| builder.build().unwrap_or_else(|_| Gitignore::empty()))
+    }
+
+    /// Add a matcher, keeping parent directories before their subdirectories
+    fn add_gitignore(&mut self, gitignore: Gitignore) {
+        self.gitignores.push(gitignore);
+        self.gitignores.sort_by_key(|gitignore| gitignore.path().components().count());
+    }
+
+    /// The ignore file whose rule decides that `path` is ignored, if any: the closest file
+    /// with an opinion on `path` wins
+    fn ignore_source(&self, path: &Path, is_dir: bool) -> Option<PathBuf> {
+        for gitignore in self.gitignores.iter().rev() {
+            if !path.starts_with(gitignore.path()) {
+                continue;
+            }
+            match gitignore.matched_path_or_any_parents(path, is_dir) {
+                ignore::Match::Ignore(glob) => {
+                    let source = glob.from().map(Path::to_path_buf);
+                    return Some(source.unwrap_or_else(|| gitignore.path().join(".gitignore")));
+                }
+                ignore::Match::Whitelist(_) => return None,
+                ignore::Match::None => {}
+            }
+        }
+        None
+    }
+
+    /// Whether the closest ignore file with an opinion on `path` ignores it
+    pub(crate) fn is_gitignored(&self, path: &Path) -> bool {
+        self.ignore_source(path, false).is_some()
+    }
+
+    /// The exclusion patterns in effect: defaults and user additions (with leading `./`
+    /// removed), then the `.gitignore`/`.adkignore` rules loaded, as written. Duplicates are
+    /// dropped (first occurrence kept).
+    pub fn effective_exclusions(&self) -> Vec<String> {
+        self.exclusions.clone()
+    }
+
+    /// Normalize the glob patterns and recompute the list returned by
+    /// [`Self::effective_exclusions`]
+    fn with_merged_exclusions(mut self) -> Self {
+        let mut patterns: Vec<String> = Vec::new();
+        for pattern in &self.excluded_patterns {
+            let pattern = pattern.trim().trim_start_matches("./").to_string();
+            if !pattern.is_empty() && !patterns.contains(&pattern) {
+                patterns.push(pattern);
+            }
+        }
+        self.excluded_patterns = patterns;
+
+        let mut exclusions = self.excluded_patterns.clone();
+        for rule in &self.ignore_rules {
+            if !exclusions.contains(rule) {
+                exclusions.push(rule.clone());
+            }
+        }
+        self.exclusions = exclusions;
+        self
+    }
+
+    /// Report settings that contradict each other, such as an allowed extension whose files
+    /// are all excluded by a pattern. An empty list means the configuration is consistent.
+    pub fn validate_config(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        if self.allowed_extensions.is_empty() {
+            problems.push("No file extensions are allowed".to_string());
+        }
+
+        if self.min_file_size > self.max_file_size {
+            problems.push(format!(
+                "Minimum file size ({}) exceeds maximum file size ({})",
+                Self::format_file_size(self.min_file_size),
+                Self::format_file_size(self.max_file_size)
+            ));
+        }
+
+        for extension in &self.allowed_extensions {
+            let probe = format!("file.{}", extension);
+            if let Some(pattern) = self
+                .excluded_patterns
+                .iter()
+                .find(|pattern| self.matches_pattern(&probe, pattern))
+            {
+                problems.push(format!(
+                    "Extension '{}' is allowed but excluded by pattern '{}'",
+                    extension, pattern
+                ));
+            }
+        }
+
+        problems
+    }
+
+    /// Classify a file and read its size with one `symlink_metadata` call; symlinks take a
+    /// second lookup to resolve their target
+    pub fn inspect<P: AsRef<Path>>(&self, file_path: P) -> Result<FileInspection> {
+        let file_path = file_path.as_ref();
+        self.inspect_path(file_path)
+            .with_context(|| format!("Failed to get metadata for {:?}", file_path))
+    }
+
+    fn inspect_path(&self, file_path: &Path) -> std::io::Result<FileInspection> {
+        let metadata = vfs::symlink_metadata(file_path)?;
+        let is_symlink = metadata.is_symlink();
+        let metadata = if is_symlink { vfs::metadata(file_path)? } else { metadata };
+
+        Ok(FileInspection {
+            file_type: self.determine_file_type(file_path),
+            size: metadata.len(),
+            is_file: metadata.is_file(),
+            is_symlink,
+        })
+    }
+
     /// Validate a single file
-    pub fn validate_file<P: AsRef<Path>>(&self, file_path: P) -> Result<FileValidationResult> {
+    pub fn validate_file<P: AsRef<Path>>(&self, file_path: P) -> DetectionResult<FileValidationResult> {
         let file_path = file_path.as_ref();
         let path_buf = file_path.to_path_buf();
 
-        // Check if file exists
-        if !file_path.exists() {
-            return Ok(FileValidationResult {
-                path: path_buf,
-                is_valid: false,
-                file_size: 0,
-                file_type: FileType::Unknown,
-                reason: Some("File does not exist".to_string()),
-            });
-        }
+        // Check if file exists (a dangling symlink counts as missing)
+        let inspection = match self.inspect_path(file_path) {
+            Ok(inspection) => inspection,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(FileValidationResult {
+                    path: path_buf,
+                    is_valid: false,
+                    file_size: 0,
+                    file_type: FileType::Unknown,
+                    reason: Some("File does not exist".to_string()),
+                });
+            }
+            Err(err) => return Err(DetectionError::Io(err)),
+        };
 
         // Check if it's actually a file (not a directory)
-        if !file_path.is_file() {
+        if !inspection.is_file {
             return Ok(FileValidationResult {
                 path: path_buf,
                 is_valid: false,
@@ -153,23 +462,16 @@ impl FileValidator {
             });
         }
 
-        // Get file metadata
-        let metadata = fs::metadata(file_path)
-            .with_context(|| format!("Failed to get metadata for {:?}", file_path))?;
-        
-        let file_size = metadata.len();
+        let FileInspection { file_type, size: file_size, .. } = inspection;
 
-        // Determine file type
-        let file_type = self.determine_file_type(file_path);
-
-        // Check if file matches excluded patterns
+        // Check if file matches excluded patterns or ignore-file rules
         if self.is_excluded_file(file_path) {
             return Ok(FileValidationResult {
                 path: path_buf,
                 is_valid: false,
                 file_size,
                 file_type,
-                reason: Some("File matches excluded pattern".to_string()),
+                reason: self.exclusion_reason(file_path),
             });
         }
 
@@ -205,6 +507,23 @@ impl FileValidator {
             });
         }
 
+        // Check line lengths in a single streaming pass over the content
+        if let Some(max_line_length) = self.max_line_length {
+            if Self::longest_line_exceeds(file_path, max_line_length)? {
+                return Ok(FileValidationResult {
+                    path: path_buf,
+                    is_valid: false,
+                    file_size,
+                    file_type,
+                    reason: Some("Line too long".to_string()),
+                });
+            }
+        }
+
+        if self.strict_parsing {
+            Self::check_structured_content(file_path)?;
+        }
+
         // File is valid
         Ok(FileValidationResult {
             path: path_buf,
@@ -215,13 +534,49 @@ impl FileValidator {
         })
     }
 
-    /// Validate multiple files and return results
+    /// Async version of [`Self::validate_file`] with identical results. Detection still does
+    /// blocking `std::fs` I/O; it runs on tokio's blocking thread pool via `spawn_blocking`,
+    /// so it needs a tokio runtime and occupies a blocking-pool thread while it runs.
+    #[cfg(feature = "tokio")]
+    pub async fn validate_file_async<P: AsRef<Path>>(&self, file_path: P) -> DetectionResult<FileValidationResult> {
+        let validator = self.clone();
+        let file_path = file_path.as_ref().to_path_buf();
+        tokio::task::spawn_blocking(move || validator.validate_file(file_path)).await?
+    }
+
+    /// Validate multiple files and return results. Once valid files exceed `max_total_bytes`,
+    /// the file that crossed the budget and all remaining ones are rejected without being read.
     pub fn validate_files<P: AsRef<Path>>(&self, file_paths: &[P]) -> Result<Vec<FileValidationResult>> {
         let mut results = Vec::new();
+        let mut total_bytes = 0u64;
+        let mut budget_exceeded = false;
         
         for file_path in file_paths {
+            if budget_exceeded {
+                results.push(FileValidationResult {
+                    path: file_path.as_ref().to_path_buf(),
+                    is_valid: false,
+                    file_size: 0,
+                    file_type: self.determine_file_type(file_path),
+                    reason: Some("Batch byte budget exceeded".to_string()),
+                });
+                continue;
+            }
+
             match self.validate_file(file_path) {
-                Ok(result) => results.push(result),
+                Ok(mut result) => {
+                    if result.is_valid {
+                        if let Some(max_total_bytes) = self.max_total_bytes {
+                            total_bytes += result.file_size;
+                            budget_exceeded = total_bytes > max_total_bytes;
+                            if budget_exceeded {
+                                result.is_valid = false;
+                                result.reason = Some("Batch byte budget exceeded".to_string());
+                            }
+                        }
+                    }
+                    results.push(result);
+                }
                 Err(e) => {
                     // Create an error result for files that couldn't be validated
                     results.push(FileValidationResult {
@@ -238,6 +593,61 @@ impl FileValidator {
         Ok(results)
     }
 
+    /// Parse a JSON, YAML or TOML file; other files are not read
+    fn check_structured_content(file_path: &Path) -> DetectionResult<()> {
+        let extension = file_path.extension().and_then(|ext| ext.to_str()).unwrap_or_default();
+        if !matches!(extension, "json" | "yaml" | "yml" | "toml") {
+            return Ok(());
+        }
+
+        let content =
+            vfs::read_to_string(file_path).map_err(|source| DetectionError::UnreadableConfig {
+                path: file_path.to_path_buf(),
+                source,
+            })?;
+        type ParseResult = std::result::Result<(), Box<dyn std::error::Error + Send + Sync>>;
+        let parsed: ParseResult = match extension {
+            "json" => serde_json::from_str::<serde_json::Value>(&content)
+                .map(|_| ())
+                .map_err(Into::into),
+            "toml" => content.parse::<toml::Table>().map(|_| ()).map_err(Into::into),
+            _ => serde_yaml::Deserializer::from_str(&content)
+                .try_for_each(|document| serde_yaml::Value::deserialize(document).map(|_| ()))
+                .map_err(Into::into),
+        };
+        parsed.map_err(|source| DetectionError::ParseError {
+            path: file_path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Check whether any line in a file is longer than the limit without loading it whole
+    fn longest_line_exceeds(file_path: &Path, max_line_length: usize) -> std::io::Result<bool> {
+        let mut reader = vfs::open(file_path)?;
+        let mut current_line_length = 0usize;
+
+        loop {
+            let buffer = reader.fill_buf()?;
+            if buffer.is_empty() {
+                return Ok(false);
+            }
+
+            for &byte in buffer {
+                if byte == b'\n' {
+                    current_line_length = 0;
+                } else {
+                    current_line_length += 1;
+                    if current_line_length > max_line_length {
+                        return Ok(true);
+                    }
+                }
+            }
+
+            let consumed = buffer.len();
+            reader.consume(consumed);
+        }
+    }
+
     /// Get all valid files from a list of validation results
     pub fn get_valid_files(results: &[FileValidationResult]) -> Vec<&FileValidationResult> {
         results.iter().filter(|r| r.is_valid).collect()
@@ -248,6 +658,46 @@ impl FileValidator {
         results.iter().filter(|r| !r.is_valid).collect()
     }
 
+    /// Count files under `root` by type, skipping excluded paths.
+    ///
+    /// Only classifies by name; files are not opened or validated, and symlinks are not followed.
+    pub fn file_type_histogram<P: AsRef<Path>>(&self, root: P) -> Result<HashMap<FileType, usize>> {
+        let root = root.as_ref();
+        let mut histogram = HashMap::new();
+        let mut pending = vec![root.to_path_buf()];
+
+        while let Some(dir) = pending.pop() {
+            let entries = vfs::read_dir(&dir)
+                .with_context(|| format!("Failed to read directory {:?}", dir))?;
+
+            for entry in entries.flatten() {
+                let path = entry.path();
+                // Match exclusions against the root-relative path so the root's own location
+                // (e.g. a checkout under `build/`) does not exclude everything
+                let relative = path.strip_prefix(root).unwrap_or(&path).to_string_lossy().to_string();
+                let metadata = match entry.metadata() {
+                    Ok(metadata) => metadata,
+                    Err(_) => continue,
+                };
+
+                if metadata.is_dir() {
+                    let excluded = self.matches_excluded_pattern(&format!("{}/", relative))
+                        || self.ignore_source(&path, true).is_some();
+                    if !excluded {
+                        pending.push(path);
+                    }
+                } else if metadata.is_file()
+                    && !self.matches_excluded_pattern(&relative)
+                    && self.ignore_source(&path, false).is_none()
+                {
+                    *histogram.entry(self.determine_file_type(&path)).or_insert(0) += 1;
+                }
+            }
+        }
+
+        Ok(histogram)
+    }
+
     /// Determine the file type based on extension and name
     fn determine_file_type<P: AsRef<Path>>(&self, file_path: P) -> FileType {
         let file_path = file_path.as_ref();
@@ -287,13 +737,10 @@ impl FileValidator {
         let file_path = file_path.as_ref();
         
         // Special handling for files without extensions
-        if let Some(filename) = file_path.file_name().and_then(|name| name.to_str()) {
-            match filename {
-                "Cargo.toml" | "requirements.txt" | "setup.py" | ".env" | ".env.template" => {
-                    return true;
-                }
-                _ => {}
-            }
+        if let Some("Cargo.toml" | "requirements.txt" | "setup.py" | ".env" | ".env.template") =
+            file_path.file_name().and_then(|name| name.to_str())
+        {
+            return true;
         }
 
         // Check extension
@@ -304,34 +751,31 @@ impl FileValidator {
         }
     }
 
-    /// Check if a file matches any excluded patterns
+    /// Check if a file matches any excluded pattern or is ignored by a loaded ignore file
     fn is_excluded_file<P: AsRef<Path>>(&self, file_path: P) -> bool {
-        let file_path = file_path.as_ref();
-        let path_str = file_path.to_string_lossy();
+        self.exclusion_reason(file_path.as_ref()).is_some()
+    }
 
-        for pattern in &self.excluded_patterns {
-            if self.matches_pattern(&path_str, pattern) {
-                return true;
-            }
+    /// Why a file is excluded: a matching excluded pattern, or the ignore file ignoring it
+    fn exclusion_reason(&self, file_path: &Path) -> Option<String> {
+        if self.matches_excluded_pattern(&file_path.to_string_lossy()) {
+            return Some("File matches excluded pattern".to_string());
         }
+        self.ignore_source(file_path, false).map(|source| {
+            let name = source.file_name().unwrap_or(source.as_os_str());
+            format!("File is ignored by {} ({})", name.to_string_lossy(), source.display())
+        })
+    }
 
-        false
+    fn matches_excluded_pattern(&self, path: &str) -> bool {
+        self.excluded_patterns
+            .iter()
+            .any(|pattern| self.matches_pattern(path, pattern))
     }
 
-    /// Simple pattern matching for exclusion patterns
+    /// Glob matching for exclusion patterns
     fn matches_pattern(&self, path: &str, pattern: &str) -> bool {
-        if pattern.contains("**") {
-            // Handle recursive patterns like "target/**"
-            let prefix = pattern.split("**").next().unwrap_or("");
-            path.contains(prefix)
-        } else if pattern.starts_with("*.") {
-            // Handle extension patterns like "*.tmp"
-            let extension = &pattern[2..];
-            path.ends_with(extension)
-        } else {
-            // Exact match or contains
-            path.contains(pattern)
-        }
+        matching::glob_match(path, pattern)
     }
 
     /// Get file size in a human-readable format
@@ -370,6 +814,98 @@ impl FileValidator {
         }
     }
 
+    /// Classify the role of a file using its path and a sample of its content
+    pub fn classify_purpose<P: AsRef<Path>>(&self, file_path: P, content_sample: &str) -> FilePurpose {
+        let file_path = file_path.as_ref();
+
+        // Generated files are flagged first since they can live anywhere
+        let generated_markers = [
+            "@generated",
+            "DO NOT EDIT",
+            "Code generated by",
+            "automatically generated",
+            "Generated by the protocol buffer compiler",
+        ];
+        if generated_markers.iter().any(|marker| content_sample.contains(marker)) {
+            return FilePurpose::Generated;
+        }
+
+        let filename = file_path.file_name().and_then(|name| name.to_str()).unwrap_or("");
+        if filename.ends_with("_pb2.py") || filename.ends_with("_pb2_grpc.py") {
+            return FilePurpose::Generated;
+        }
+
+        // Test files by directory
+        let in_test_dir = file_path.components().any(|component| {
+            matches!(
+                component.as_os_str().to_str(),
+                Some("tests" | "test" | "benches" | "fixtures")
+            )
+        });
+        if in_test_dir {
+            return FilePurpose::Test;
+        }
+
+        // Test files by naming convention
+        if (filename.starts_with("test_") && filename.ends_with(".py"))
+            || filename.ends_with("_test.py")
+            || filename.ends_with("_test.rs")
+            || filename == "conftest.py"
+        {
+            return FilePurpose::Test;
+        }
+
+        // Test files by content markers
+        let test_markers = ["import pytest", "from pytest", "import unittest", "from unittest"];
+        if test_markers.iter().any(|marker| content_sample.contains(marker)) {
+            return FilePurpose::Test;
+        }
+
+        match self.determine_file_type(file_path) {
+            FileType::Config | FileType::Environment | FileType::Build => FilePurpose::Config,
+            FileType::Documentation => FilePurpose::Documentation,
+            FileType::Rust | FileType::Python | FileType::Unknown => FilePurpose::Source,
+        }
+    }
+
+    /// Assemble a review plan from validation results.
+    ///
+    /// Valid files are taken largest first; generated files are skipped unless requested, and
+    /// a file that would overflow the byte cap is skipped while smaller ones may still fit.
+    pub fn build_review_plan(&self, results: &[FileValidationResult], options: &ReviewPlanOptions) -> ReviewPlan {
+        let mut candidates = Self::get_valid_files(results);
+        candidates.sort_by(|a, b| b.file_size.cmp(&a.file_size).then_with(|| a.path.cmp(&b.path)));
+
+        let mut plan = ReviewPlan::default();
+        for result in candidates {
+            if !options.include_generated {
+                let sample = Self::read_content_sample(&result.path);
+                if self.classify_purpose(&result.path, &sample) == FilePurpose::Generated {
+                    plan.skipped.push((result.path.clone(), "Generated file".to_string()));
+                    continue;
+                }
+            }
+
+            if let Some(max_total_bytes) = options.max_total_bytes {
+                if plan.total_bytes + result.file_size > max_total_bytes {
+                    plan.skipped.push((result.path.clone(), "Review byte cap exceeded".to_string()));
+                    continue;
+                }
+            }
+
+            plan.total_bytes += result.file_size;
+            plan.groups.entry(result.file_type.clone()).or_default().push(result.clone());
+        }
+
+        plan
+    }
+
+    /// Read the first few KB of a file for content-based classification
+    fn read_content_sample(file_path: &Path) -> String {
+        let sample = vfs::read_prefix(file_path, 4096).unwrap_or_default();
+        String::from_utf8_lossy(&sample).into_owned()
+    }
+
     /// Get statistics about a collection of files
     pub fn get_file_statistics(results: &[FileValidationResult]) -> FileStatistics {
         let mut stats = FileStatistics::default();
@@ -401,6 +937,24 @@ impl FileValidator {
     }
 }
 
+/// Options for [`FileValidator::build_review_plan`]
+#[derive(Debug, Clone, Default)]
+pub struct ReviewPlanOptions {
+    /// Maximum cumulative size of planned files
+    pub max_total_bytes: Option<u64>,
+    /// Keep machine-generated files in the plan
+    pub include_generated: bool,
+}
+
+/// Valid files selected for review, grouped by type with the largest files first
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReviewPlan {
+    pub groups: HashMap<FileType, Vec<FileValidationResult>>,
+    pub total_bytes: u64,
+    /// Valid files left out of the plan, with the reason
+    pub skipped: Vec<(PathBuf, String)>,
+}
+
 /// Statistics about a collection of files
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct FileStatistics {
@@ -436,6 +990,47 @@ impl FileStatistics {
             self.total_size / self.total_files as u64
         }
     }
+
+    /// Accumulate another set of statistics into this one
+    pub fn merge(&mut self, other: &FileStatistics) {
+        // Destructure so that adding a field without merging it fails to compile
+        let FileStatistics {
+            total_files,
+            valid_files,
+            invalid_files,
+            total_size,
+            valid_size,
+            rust_files,
+            python_files,
+            config_files,
+            doc_files,
+            env_files,
+            build_files,
+            unknown_files,
+        } = other;
+
+        self.total_files += total_files;
+        self.valid_files += valid_files;
+        self.invalid_files += invalid_files;
+        self.total_size += total_size;
+        self.valid_size += valid_size;
+        self.rust_files += rust_files;
+        self.python_files += python_files;
+        self.config_files += config_files;
+        self.doc_files += doc_files;
+        self.env_files += env_files;
+        self.build_files += build_files;
+        self.unknown_files += unknown_files;
+    }
+
+    /// Get the grand total of statistics gathered across multiple projects
+    pub fn sum(stats: &[FileStatistics]) -> FileStatistics {
+        let mut total = FileStatistics::default();
+        for stat in stats {
+            total.merge(stat);
+        }
+        total
+    }
 }
 
 #[cfg(test)]
@@ -459,6 +1054,63 @@ mod tests {
         assert!(result.reason.is_none());
     }
 
+    #[test]
+    fn test_inspect_reads_metadata_once() {
+        let temp_dir = TempDir::new().unwrap();
+        let rust_file = temp_dir.path().join("lib.rs");
+        fs::write(&rust_file, "pub fn answer() -> u32 { 42 }").unwrap();
+        let validator = FileValidator::default();
+        let reads = vfs::metadata_calls;
+
+        let before = reads();
+        let inspection = validator.inspect(&rust_file).unwrap();
+        assert_eq!(reads() - before, 1);
+        assert_eq!(
+            inspection,
+            FileInspection {
+                file_type: FileType::Rust,
+                size: 29,
+                is_file: true,
+                is_symlink: false,
+            }
+        );
+
+        let before = reads();
+        assert!(validator.validate_file(&rust_file).unwrap().is_valid);
+        assert_eq!(reads() - before, 1);
+
+        #[cfg(unix)]
+        {
+            let link = temp_dir.path().join("link.rs");
+            std::os::unix::fs::symlink(&rust_file, &link).unwrap();
+            let before = reads();
+            let inspection = validator.inspect(&link).unwrap();
+            assert_eq!(reads() - before, 2);
+            assert!(inspection.is_symlink);
+            assert_eq!(inspection.size, 29);
+        }
+    }
+
+    #[test]
+    fn test_validate_file_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = temp_dir.path().join("config.json");
+        fs::write(&config, r#"{"model": "#).unwrap();
+
+        // Contents are only parsed when parsing is strict
+        assert!(FileValidator::default().validate_file(&config).unwrap().is_valid);
+        let strict = FileValidator::default().with_strict_parsing(true);
+        let err = strict.validate_file(&config).unwrap_err();
+        assert!(matches!(err, DetectionError::ParseError { ref path, .. } if *path == config));
+
+        // A path below a file fails to resolve rather than counting as missing
+        let err = strict.validate_file(config.join("nested.json")).unwrap_err();
+        assert!(matches!(err, DetectionError::Io(_)));
+
+        fs::write(&config, r#"{"model": "gemini-2.0-flash"}"#).unwrap();
+        assert!(strict.validate_file(&config).unwrap().is_valid);
+    }
+
     #[test]
     fn test_validate_large_file() {
         let temp_dir = TempDir::new().unwrap();
@@ -476,6 +1128,24 @@ mod tests {
         assert!(result.reason.unwrap().contains("too large"));
     }
 
+    #[test]
+    fn test_validate_long_line_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let minified_file = temp_dir.path().join("minified.json");
+        let normal_file = temp_dir.path().join("normal.json");
+
+        fs::write(&minified_file, "x".repeat(1024 * 1024)).unwrap(); // 1MB single line
+        fs::write(&normal_file, "{\n  \"key\": \"value\"\n}\n").unwrap();
+
+        let validator = FileValidator::default().with_max_line_length(10_000);
+
+        let result = validator.validate_file(&minified_file).unwrap();
+        assert!(!result.is_valid);
+        assert_eq!(result.reason, Some("Line too long".to_string()));
+
+        assert!(validator.validate_file(&normal_file).unwrap().is_valid);
+    }
+
     #[test]
     fn test_validate_excluded_file() {
         let temp_dir = TempDir::new().unwrap();
@@ -493,6 +1163,214 @@ mod tests {
         assert!(result.reason.unwrap().contains("excluded pattern"));
     }
 
+    #[test]
+    fn test_gitignore_exclusion() {
+        let temp_dir = TempDir::new().unwrap();
+        let src = temp_dir.path().join("src");
+        fs::create_dir(&src).unwrap();
+        fs::write(temp_dir.path().join(".gitignore"), "*.generated.rs\n").unwrap();
+        fs::write(src.join(".gitignore"), "!keep.generated.rs\n").unwrap();
+        fs::write(src.join("schema.generated.rs"), "pub struct Schema;").unwrap();
+        fs::write(src.join("keep.generated.rs"), "pub struct Keep;").unwrap();
+        fs::write(src.join("lib.rs"), "pub mod schema;").unwrap();
+
+        let validator = FileValidator::default().with_gitignore(temp_dir.path());
+        assert!(validator.effective_exclusions().contains(&"*.generated.rs".to_string()));
+        assert!(validator.is_excluded_file(src.join("schema.generated.rs")));
+
+        let result = validator.validate_file(src.join("schema.generated.rs")).unwrap();
+        assert!(!result.is_valid);
+        assert!(result.reason.unwrap().contains("gitignore"));
+
+        // Nested .gitignore files can re-include files
+        assert!(validator.validate_file(src.join("keep.generated.rs")).unwrap().is_valid);
+        assert!(validator.validate_file(src.join("lib.rs")).unwrap().is_valid);
+    }
+
+    #[test]
+    fn test_gitignores_above_path() {
+        let temp_dir = TempDir::new().unwrap();
+        for dir in ["src/nested", "docs", "build/out"] {
+            fs::create_dir_all(temp_dir.path().join(dir)).unwrap();
+        }
+        fs::write(temp_dir.path().join(".gitignore"), "build/\n").unwrap();
+        fs::write(temp_dir.path().join("src/.gitignore"), ".env\n").unwrap();
+        fs::write(temp_dir.path().join("docs/.gitignore"), "*\n").unwrap();
+        fs::write(temp_dir.path().join("build/out/.gitignore"), "!.env\n").unwrap();
+
+        // Only the ancestors' .gitignore files are read; nothing else is listed or inspected
+        let before = vfs::metadata_calls();
+        let env = temp_dir.path().join("src/nested/.env");
+        let validator = FileValidator::default().with_gitignores_above(temp_dir.path(), &env);
+        assert_eq!(vfs::metadata_calls(), before);
+        assert!(validator.is_gitignored(&env));
+        assert!(!validator.effective_exclusions().contains(&"*".to_string()));
+
+        // A .gitignore below an ignored directory cannot re-include files
+        let env = temp_dir.path().join("build/out/.env");
+        let validator = FileValidator::default().with_gitignores_above(temp_dir.path(), &env);
+        assert!(validator.is_gitignored(&env));
+        assert!(!validator.effective_exclusions().contains(&"!.env".to_string()));
+    }
+
+    #[test]
+    fn test_exclusions_do_not_match_substrings() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join("src")).unwrap();
+        let validator = FileValidator::default();
+
+        // `target/**` only matches a `target` path segment
+        let helper = temp_dir.path().join("src/my-target-helper.rs");
+        fs::write(&helper, "pub fn help() {}").unwrap();
+        assert!(validator.validate_file(&helper).unwrap().is_valid);
+
+        // `*.tmp` needs a `.tmp` extension on the file name
+        let tmp = temp_dir.path().join("setup.tmp");
+        fs::write(&tmp, "scratch").unwrap();
+        assert!(!validator.validate_file(&tmp).unwrap().is_valid);
+        assert!(!validator.is_excluded_file(temp_dir.path().join("atmp")));
+    }
+
+    #[test]
+    fn test_effective_exclusions() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join(".gitignore"), "# build output\n/site/\n*.cache\n!keep.cache\n").unwrap();
+        fs::write(temp_dir.path().join(".adkignore"), "eval_runs\n").unwrap();
+
+        let validator = FileValidator::default()
+            .with_excluded_pattern("./fixtures/**")
+            .with_excluded_pattern("target/**")
+            .with_ignore_files(temp_dir.path());
+        let exclusions = validator.effective_exclusions();
+
+        assert!(exclusions.contains(&"fixtures/**".to_string()));
+        assert!(exclusions.contains(&"/site/".to_string()));
+        assert!(exclusions.contains(&"*.cache".to_string()));
+        assert!(exclusions.contains(&"!keep.cache".to_string()));
+        assert!(exclusions.contains(&"eval_runs".to_string()));
+        assert!(!exclusions.iter().any(|pattern| pattern.starts_with('#')));
+        assert_eq!(exclusions.iter().filter(|pattern| *pattern == "target/**").count(), 1);
+
+        // Ignore-file rules keep gitignore semantics: anchoring, negation, precedence
+        fs::create_dir_all(temp_dir.path().join("site")).unwrap();
+        fs::create_dir_all(temp_dir.path().join("docs/site")).unwrap();
+        for file in ["site/index.md", "docs/site/index.md", "notes.cache", "keep.cache"] {
+            fs::write(temp_dir.path().join(file), "# Docs").unwrap();
+        }
+        let result = validator.validate_file(temp_dir.path().join("site/index.md")).unwrap();
+        assert!(result.reason.unwrap().starts_with("File is ignored by .gitignore"));
+        let nested = temp_dir.path().join("docs/site/index.md");
+        assert!(validator.validate_file(nested).unwrap().is_valid);
+        assert!(validator.is_excluded_file(temp_dir.path().join("notes.cache")));
+        assert!(!validator.is_excluded_file(temp_dir.path().join("keep.cache")));
+
+        fs::create_dir(temp_dir.path().join("eval_runs")).unwrap();
+        fs::write(temp_dir.path().join("eval_runs/run.json"), "{}").unwrap();
+        let result = validator.validate_file(temp_dir.path().join("eval_runs/run.json")).unwrap();
+        assert!(result.reason.unwrap().starts_with("File is ignored by .adkignore"));
+    }
+
+    #[test]
+    fn test_build_review_plan() {
+        let temp_dir = TempDir::new().unwrap();
+        let files = [
+            ("small.rs", "x".repeat(50)),
+            ("big.rs", "x".repeat(300)),
+            ("agent.py", "x".repeat(200)),
+            ("schema.rs", format!("// @generated\n{}", "x".repeat(100))),
+            ("README.md", "x".repeat(80)),
+        ];
+        let paths: Vec<_> = files
+            .iter()
+            .map(|(name, content)| {
+                let path = temp_dir.path().join(name);
+                fs::write(&path, content).unwrap();
+                path
+            })
+            .collect();
+
+        let validator = FileValidator::default();
+        let results = validator.validate_files(&paths).unwrap();
+        let options = ReviewPlanOptions { max_total_bytes: Some(450), ..Default::default() };
+        let plan = validator.build_review_plan(&results, &options);
+
+        // Largest first within each group; agent.py no longer fits once big.rs is planned
+        let names = |file_type: FileType| -> Vec<String> {
+            plan.groups.get(&file_type).map_or_else(Vec::new, |group| {
+                group
+                    .iter()
+                    .map(|r| r.path.file_name().unwrap().to_string_lossy().into_owned())
+                    .collect()
+            })
+        };
+        assert_eq!(names(FileType::Rust), vec!["big.rs", "small.rs"]);
+        assert_eq!(names(FileType::Documentation), vec!["README.md"]);
+        assert!(!plan.groups.contains_key(&FileType::Python));
+        assert_eq!(plan.total_bytes, 430);
+        assert_eq!(
+            plan.skipped,
+            vec![
+                (temp_dir.path().join("agent.py"), "Review byte cap exceeded".to_string()),
+                (temp_dir.path().join("schema.rs"), "Generated file".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_batch_byte_budget() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths: Vec<_> = ["a.rs", "b.rs", "c.rs", "d.rs"]
+            .iter()
+            .map(|name| {
+                let path = temp_dir.path().join(name);
+                fs::write(&path, "x".repeat(40)).unwrap();
+                path
+            })
+            .collect();
+
+        let validator = FileValidator::default().with_max_total_bytes(100);
+        let results = validator.validate_files(&paths).unwrap();
+
+        assert!(results[0].is_valid);
+        assert!(results[1].is_valid);
+        for result in &results[2..] {
+            assert!(!result.is_valid);
+            assert_eq!(result.reason.as_deref(), Some("Batch byte budget exceeded"));
+        }
+
+        // Files after the budget is spent are not looked at, so even a missing one is rejected
+        // for the budget rather than reported as missing
+        let mut paths = paths;
+        paths.push(temp_dir.path().join("missing.rs"));
+        let results = validator.validate_files(&paths).unwrap();
+        assert_eq!(results[4].reason.as_deref(), Some("Batch byte budget exceeded"));
+        assert_eq!(results[4].file_type, FileType::Rust);
+        assert_eq!(results[4].file_size, 0);
+    }
+
+    #[test]
+    fn test_validate_config() {
+        assert!(FileValidator::default().validate_config().is_empty());
+        assert!(FileValidator::for_code_review().validate_config().is_empty());
+
+        let overlapping = FileValidator::default()
+            .with_allowed_extensions(&["rs", ".log"])
+            .with_excluded_pattern("*.log");
+        assert_eq!(
+            overlapping.validate_config(),
+            vec!["Extension 'log' is allowed but excluded by pattern '*.log'".to_string()]
+        );
+
+        let no_extensions = FileValidator::default().with_allowed_extensions(&[]);
+        assert_eq!(no_extensions.validate_config(), vec!["No file extensions are allowed".to_string()]);
+
+        let inverted_sizes = FileValidator::new(100, 1024);
+        assert_eq!(
+            inverted_sizes.validate_config(),
+            vec!["Minimum file size (1.0 KB) exceeds maximum file size (100 B)".to_string()]
+        );
+    }
+
     #[test]
     fn test_file_type_detection() {
         let validator = FileValidator::default();
@@ -558,6 +1436,117 @@ mod tests {
         assert_eq!(stats.valid_size, 1500);
     }
 
+    #[test]
+    fn test_file_type_histogram() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("src/detection")).unwrap();
+        fs::create_dir_all(root.join("target/debug")).unwrap();
+        fs::write(root.join("Cargo.toml"), "[package]").unwrap();
+        fs::write(root.join("README.md"), "# Docs").unwrap();
+        fs::write(root.join("config.json"), "{}").unwrap();
+        fs::write(root.join("src/main.rs"), "fn main() {}").unwrap();
+        fs::write(root.join("src/lib.rs"), "").unwrap();
+        fs::write(root.join("src/detection/mod.rs"), "").unwrap();
+        fs::write(root.join("src/tool.py"), "").unwrap();
+        fs::write(root.join("debug.log"), "").unwrap();
+        fs::write(root.join("target/debug/build.rs"), "").unwrap();
+
+        let validator = FileValidator::default();
+        let histogram = validator.file_type_histogram(root).unwrap();
+
+        assert_eq!(histogram.get(&FileType::Rust), Some(&3));
+        assert_eq!(histogram.get(&FileType::Python), Some(&1));
+        assert_eq!(histogram.get(&FileType::Build), Some(&1));
+        assert_eq!(histogram.get(&FileType::Config), Some(&1));
+        assert_eq!(histogram.get(&FileType::Documentation), Some(&1));
+        assert_eq!(histogram.values().sum::<usize>(), 7);
+    }
+
+    #[test]
+    fn test_merge_file_statistics() {
+        let first = FileStatistics {
+            total_files: 4,
+            valid_files: 3,
+            invalid_files: 1,
+            total_size: 400,
+            valid_size: 300,
+            rust_files: 2,
+            python_files: 0,
+            config_files: 1,
+            doc_files: 1,
+            env_files: 0,
+            build_files: 0,
+            unknown_files: 0,
+        };
+        let second = FileStatistics {
+            total_files: 6,
+            valid_files: 2,
+            invalid_files: 4,
+            total_size: 600,
+            valid_size: 100,
+            rust_files: 0,
+            python_files: 3,
+            config_files: 0,
+            doc_files: 0,
+            env_files: 1,
+            build_files: 1,
+            unknown_files: 1,
+        };
+
+        let mut merged = first.clone();
+        merged.merge(&second);
+
+        assert_eq!(merged.total_files, 10);
+        assert_eq!(merged.valid_files, 5);
+        assert_eq!(merged.invalid_files, 5);
+        assert_eq!(merged.total_size, 1000);
+        assert_eq!(merged.valid_size, 400);
+        assert_eq!(merged.rust_files, 2);
+        assert_eq!(merged.python_files, 3);
+        assert_eq!(merged.config_files, 1);
+        assert_eq!(merged.doc_files, 1);
+        assert_eq!(merged.env_files, 1);
+        assert_eq!(merged.build_files, 1);
+        assert_eq!(merged.unknown_files, 1);
+        assert_eq!(merged.valid_percentage(), 50.0);
+        assert_eq!(merged.average_file_size(), 100);
+
+        let summed = FileStatistics::sum(&[first, second]);
+        assert_eq!(summed.total_files, merged.total_files);
+        assert_eq!(summed.valid_percentage(), merged.valid_percentage());
+    }
+
+    #[test]
+    fn test_classify_purpose() {
+        let validator = FileValidator::default();
+
+        assert_eq!(
+            validator.classify_purpose(Path::new("src/lib.rs"), "pub mod detection;"),
+            FilePurpose::Source
+        );
+        assert_eq!(
+            validator.classify_purpose(Path::new("tests/it.rs"), "#[test]\nfn it_works() {}"),
+            FilePurpose::Test
+        );
+        assert_eq!(
+            validator.classify_purpose(Path::new("agent/test_agent.py"), "def test_run(): pass"),
+            FilePurpose::Test
+        );
+        assert_eq!(
+            validator.classify_purpose(Path::new("src/proto.rs"), "// @generated by prost-build"),
+            FilePurpose::Generated
+        );
+        assert_eq!(
+            validator.classify_purpose(Path::new("Cargo.toml"), "[package]"),
+            FilePurpose::Config
+        );
+        assert_eq!(
+            validator.classify_purpose(Path::new("docs/guide.md"), "# Guide"),
+            FilePurpose::Documentation
+        );
+    }
+
     #[test]
     fn test_format_file_size() {
         assert_eq!(FileValidator::format_file_size(500), "500 B");
@@ -4,6 +4,7 @@ use tempfile::TempDir;
 
 /// Integration tests for ADK project detection utilities
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod integration_tests {
     use super::*;
 
@@ -98,6 +99,20 @@ pub use agent::*;
         assert!(config_info.has_adk_config);
         assert!(config_info.google_api_configured);
         assert!(config_info.mcp_server_configured);
+        assert_eq!(
+            config_info.mcp_servers,
+            vec![McpServerInfo {
+                name: "arkaft-google-adk".to_string(),
+                command: Some("./arkaft-mcp-google-adk/target/release/arkaft-mcp-google-adk".to_string()),
+                args: Vec::new(),
+                disabled: false,
+                auto_approve: vec!["adk_query".to_string(), "review_rust_file".to_string()],
+            }]
+        );
+        assert_eq!(
+            config_detector.parse_mcp_config(&kiro_dir.join("mcp.json")).unwrap(),
+            config_info.mcp_servers
+        );
         assert_eq!(config_info.adk_version, Some("1.0.0".to_string()));
         assert!(config_info.environment_variables.contains_key("GOOGLE_API_KEY"));
 
@@ -334,6 +349,119 @@ serde = "1.0"
         assert!(python_project.is_some());
     }
 
+    #[test]
+    fn test_detection_profile_size_limits() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_root = temp_dir.path();
+
+        // 2MB: over the 1MB code review limit, under the 10MB project analysis limit
+        let large = "x".repeat(2 * 1024 * 1024);
+        fs::write(project_root.join("large.rs"), &large).unwrap();
+        fs::write(project_root.join("config.json"), format!("{{\"GOOGLE_API_KEY\": \"{}\"}}", large)).unwrap();
+
+        let review = crate::DetectionProfile::code_review();
+        assert_eq!(review.config().max_file_size, 1024 * 1024);
+        assert!(!review.validator().validate_file(project_root.join("large.rs")).unwrap().is_valid);
+        let config_info = review.config_detector().detect_adk_config(project_root).unwrap();
+        assert!(config_info.config_files[0].skip_reason.is_some());
+
+        let analysis = crate::DetectionProfile::project_analysis();
+        assert!(analysis.validator().validate_file(project_root.join("large.rs")).unwrap().is_valid);
+        let config_info = analysis.config_detector().detect_adk_config(project_root).unwrap();
+        assert!(config_info.config_files[0].skip_reason.is_none());
+        assert!(config_info.google_api_configured);
+    }
+
+    #[test]
+    fn test_detection_config_from_env() {
+        let env = |vars: &[(&str, &str)]| -> std::collections::HashMap<String, String> {
+            vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+        };
+
+        let vars = env(&[
+            ("ADK_DETECT_MAX_DEPTH", "7"),
+            ("ADK_DETECT_MAX_FILE_SIZE", "2048"),
+            ("ADK_DETECT_FOLLOW_SYMLINKS", "yes"),
+            ("ADK_DETECT_SKIP_DIRS", "target, dist"),
+        ]);
+        let config = crate::DetectionConfig::from_env_with(|key| vars.get(key).cloned()).unwrap();
+        assert_eq!(config.max_depth, 7);
+        assert_eq!(config.max_file_size, 2048);
+        assert!(config.follow_symlinks);
+        assert!(!config.include_build_artifacts);
+        assert_eq!(config.skip_dirs, vec!["target", "dist"]);
+
+        let vars = env(&[("ADK_DETECT_MAX_DEPTH", "deep")]);
+        let err = crate::DetectionConfig::from_env_with(|key| vars.get(key).cloned()).unwrap_err();
+        assert!(matches!(
+            err,
+            crate::DetectionError::InvalidEnvVar { ref key, .. } if key == "ADK_DETECT_MAX_DEPTH"
+        ));
+    }
+
+    #[test]
+    fn test_detection_config_from_process_env() {
+        const KEYS: &[&str] = &["ADK_DETECT_MAX_DEPTH", "ADK_DETECT_RELATIVE_PATHS"];
+        let saved: Vec<_> = KEYS.iter().map(|key| (*key, std::env::var_os(key))).collect();
+        let restore = || {
+            for (key, value) in &saved {
+                match value {
+                    Some(value) => std::env::set_var(key, value),
+                    None => std::env::remove_var(key),
+                }
+            }
+        };
+
+        std::env::set_var("ADK_DETECT_MAX_DEPTH", "3");
+        std::env::set_var("ADK_DETECT_RELATIVE_PATHS", "1");
+        let config = crate::DetectionConfig::from_env();
+        std::env::set_var("ADK_DETECT_RELATIVE_PATHS", "maybe");
+        let err = crate::DetectionConfig::from_env();
+        restore();
+
+        let config = config.unwrap();
+        assert_eq!(config.max_depth, 3);
+        assert!(config.relative_paths);
+        let Err(crate::DetectionError::InvalidEnvVar { key, value, .. }) = err else {
+            panic!("expected an invalid variable, got {:?}", err);
+        };
+        assert_eq!((key.as_str(), value.as_str()), ("ADK_DETECT_RELATIVE_PATHS", "maybe"));
+    }
+
+    #[test]
+    fn test_is_compatible_adk_version() {
+        assert_eq!(crate::supported_adk_range(), ">=0.1, <2.0");
+        assert!(crate::is_compatible_adk_version("1.0.0"));
+        assert!(crate::is_compatible_adk_version("0.1.0"));
+        assert!(crate::is_compatible_adk_version("1.0.0-rc1"));
+        assert!(!crate::is_compatible_adk_version("2.0.0"));
+        assert!(!crate::is_compatible_adk_version("2.0.0-rc1"));
+        assert!(!crate::is_compatible_adk_version("0.0.9"));
+        assert!(!crate::is_compatible_adk_version("not-a-version"));
+        assert!(!crate::is_compatible_adk_version(""));
+    }
+
+    #[test]
+    fn test_check_adk_version_reasons() {
+        let in_range = crate::check_adk_version("1.4.0");
+        assert!(in_range.compatible);
+        assert_eq!(in_range.parsed, Some(semver::Version::new(1, 4, 0)));
+        assert_eq!(in_range.required_range.to_string(), ">=0.1, <2.0");
+        assert_eq!(in_range.reason, "1.4.0 is within the supported range >=0.1, <2.0");
+
+        let out_of_range = crate::check_adk_version("2.1.0");
+        assert!(!out_of_range.compatible);
+        assert_eq!(out_of_range.parsed, Some(semver::Version::new(2, 1, 0)));
+        assert!(out_of_range.reason.contains("outside the supported range"));
+
+        let unparseable = crate::check_adk_version("latest");
+        assert!(!unparseable.compatible);
+        assert_eq!(unparseable.parsed, None);
+        assert!(unparseable.reason.starts_with("'latest' is not a semver version"));
+
+        assert_eq!(crate::check_adk_version(" ").reason, "No ADK version given");
+    }
+
     #[test]
     fn test_configuration_validation_and_recommendations() {
         let temp_dir = TempDir::new().unwrap();
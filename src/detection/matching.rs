@@ -0,0 +1,269 @@
+use glob::{MatchOptions, Pattern};
+
+/// Match a path against a glob pattern at any directory boundary.
+///
+/// Relative patterns match a trailing run of path components, so `target/**` matches
+/// `crates/app/target/debug/app` but not `my_target/debug/app`, and `*.tmp` matches
+/// `cache/file.tmp` but not `file.xtmp`. A `dir/**` pattern also matches `dir` itself.
+/// Invalid patterns match nothing.
+pub fn glob_match(path: &str, pattern: &str) -> bool {
+    let glob = match Pattern::new(pattern.trim_start_matches('/')) {
+        Ok(glob) => glob,
+        Err(_) => return false,
+    };
+    let options = MatchOptions {
+        case_sensitive: true,
+        require_literal_separator: true,
+        require_literal_leading_dot: false,
+    };
+
+    let normalized = path.replace('\\', "/");
+    let components: Vec<&str> = normalized
+        .split('/')
+        .filter(|component| !component.is_empty() && *component != ".")
+        .collect();
+    let dir_prefix = pattern.strip_suffix("/**");
+
+    (0..components.len()).any(|start| {
+        let candidate = components[start..].join("/");
+        glob.matches_with(&candidate, options) || dir_prefix == Some(candidate.as_str())
+    })
+}
+
+/// Normalize a package name per PEP 503: lowercase with runs of `-`, `_` and `.` collapsed to `-`
+fn normalize_dep_name(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+    let mut last_was_separator = false;
+
+    for c in name.trim().chars() {
+        if matches!(c, '-' | '_' | '.') {
+            if !last_was_separator {
+                normalized.push('-');
+            }
+            last_was_separator = true;
+        } else {
+            normalized.extend(c.to_lowercase());
+            last_was_separator = false;
+        }
+    }
+
+    normalized
+}
+
+/// Check whether a declared dependency name refers to `dependency`.
+///
+/// Names are compared whole after normalization, so `Google_ADK` matches `google-adk`
+/// but `google-adk-extras` does not.
+pub fn normalized_dep_match(declared: &str, dependency: &str) -> bool {
+    let declared = normalize_dep_name(declared);
+    !declared.is_empty() && declared == normalize_dep_name(dependency)
+}
+
+/// Find the version requirement declared for the first of `dep_names` in TOML manifest content.
+///
+/// Both `dep = "1.0"` and `dep = { version = "1.0" }` are recognized in `[dependencies]`,
+/// `[dev-dependencies]`, `[build-dependencies]`, `[workspace.dependencies]` and
+/// `[target.'cfg(..)'.dependencies]`, as well as at the top level of a manifest fragment.
+/// Names are compared with [`normalized_dep_match`], so `google-adk-extras` does not count as
+/// `google-adk`, and renamed entries (`adk = { package = "google-adk" }`) count under their
+/// real crate name. Path, git and `workspace = true` dependencies have no version; content
+/// that is not TOML gives `None`.
+pub fn extract_dependency_version(content: &str, dep_names: &[&str]) -> Option<String> {
+    const DEPENDENCY_TABLES: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
+
+    let manifest = content.parse::<toml::Table>().ok()?;
+    let nested =
+        |table: &toml::Table, key: &str| table.get(key).and_then(|v| v.as_table()).cloned();
+
+    let mut tables = vec![manifest.clone()];
+    tables.extend(
+        DEPENDENCY_TABLES
+            .iter()
+            .filter_map(|key| nested(&manifest, key)),
+    );
+    if let Some(workspace) = nested(&manifest, "workspace") {
+        tables.extend(nested(&workspace, "dependencies"));
+    }
+    if let Some(targets) = nested(&manifest, "target") {
+        for target in targets.values().filter_map(|v| v.as_table()) {
+            tables.extend(
+                DEPENDENCY_TABLES
+                    .iter()
+                    .filter_map(|key| nested(target, key)),
+            );
+        }
+    }
+
+    dep_names.iter().find_map(|dep| {
+        tables.iter().find_map(|table| {
+            table
+                .iter()
+                .filter(|(name, spec)| {
+                    let package = spec.get("package").and_then(|p| p.as_str());
+                    normalized_dep_match(package.unwrap_or(name), dep)
+                })
+                .find_map(|(_, spec)| match spec {
+                    toml::Value::String(version) => Some(version.clone()),
+                    toml::Value::Table(spec) => spec.get("version")?.as_str().map(str::to_string),
+                    _ => None,
+                })
+        })
+    })
+}
+
+/// Check whether `content` contains `token` without it being part of a longer alphanumeric run.
+///
+/// `-`, `_` and punctuation count as boundaries, so `VERTEXAI` matches in `VERTEXAI_PROJECT`
+/// and `google-cloud` in `google-cloud-aiplatform`, while `rmcp` does not match in `termcpy`.
+pub fn content_contains_token(content: &str, token: &str) -> bool {
+    if token.is_empty() {
+        return false;
+    }
+
+    let is_word_char = |c: char| c.is_alphanumeric();
+    let starts_with_word = token.starts_with(is_word_char);
+    let ends_with_word = token.ends_with(is_word_char);
+
+    content.match_indices(token).any(|(start, _)| {
+        let end = start + token.len();
+        let before_ok = !starts_with_word || !content[..start].ends_with(is_word_char);
+        let after_ok = !ends_with_word || !content[end..].starts_with(is_word_char);
+        before_ok && after_ok
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        // Recursive directory patterns only match whole components
+        assert!(glob_match("target/debug/app", "target/**"));
+        assert!(glob_match("/home/me/project/target/debug/app", "target/**"));
+        assert!(glob_match("target/", "target/**"));
+        assert!(!glob_match("my_target/debug/app", "target/**"));
+        assert!(!glob_match("src/targeting.rs", "target/**"));
+
+        // Extension patterns require the dot
+        assert!(glob_match("logs/run.log", "*.log"));
+        assert!(!glob_match("notes/catalog", "*.log"));
+        assert!(!glob_match("file.xtmp", "*.tmp"));
+
+        // `*` does not cross directories
+        assert!(glob_match("config/app.json", "config/*.json"));
+        assert!(!glob_match("config/nested/app.json", "config/*.json"));
+
+        assert!(glob_match("C:\\work\\.venv\\lib\\x.py", ".venv/**"));
+        assert!(!glob_match("anything", "[unclosed"));
+    }
+
+    #[test]
+    fn test_normalized_dep_match() {
+        assert!(normalized_dep_match("google-adk", "google-adk"));
+        assert!(normalized_dep_match("Google_ADK", "google-adk"));
+        assert!(normalized_dep_match("google.adk", "google-adk"));
+        assert!(normalized_dep_match("google--adk ", "google-adk"));
+        assert!(!normalized_dep_match("google-adk-extras", "google-adk"));
+        assert!(!normalized_dep_match("not-google-adk", "google-adk"));
+        assert!(!normalized_dep_match("", "google-adk"));
+    }
+
+    #[test]
+    fn test_extract_dependency_version() {
+        let adk = &["google-adk"];
+
+        // Inline table form, as a manifest fragment and inside [dependencies]
+        assert_eq!(
+            extract_dependency_version(r#"google-adk = { version = "1.2.3" }"#, adk),
+            Some("1.2.3".to_string())
+        );
+        assert_eq!(
+            extract_dependency_version(
+                "[dependencies]\ngoogle-adk = { version = \"1.0.0\", features = [\"full\"] }\n",
+                adk
+            ),
+            Some("1.0.0".to_string())
+        );
+
+        // Plain string form, which the line-based parsers missed
+        assert_eq!(
+            extract_dependency_version("[dependencies]\ngoogle-adk = \"0.9\"\n", adk),
+            Some("0.9".to_string())
+        );
+
+        // Multi-line tables, workspace and target-specific dependencies
+        assert_eq!(
+            extract_dependency_version("[dependencies.google-adk]\nversion = \"2.0\"\n", adk),
+            Some("2.0".to_string())
+        );
+        assert_eq!(
+            extract_dependency_version("[workspace.dependencies]\ngoogle-adk = \"1.4\"\n", adk),
+            Some("1.4".to_string())
+        );
+        assert_eq!(
+            extract_dependency_version(
+                "[target.'cfg(unix)'.dev-dependencies]\ngoogle-adk = \"1.5\"\n",
+                adk
+            ),
+            Some("1.5".to_string())
+        );
+
+        // A path before the version was picked up as the version by the config detector
+        assert_eq!(
+            extract_dependency_version(r#"google-adk = { path = "../adk", version = "1.1" }"#, adk),
+            Some("1.1".to_string())
+        );
+
+        // Renamed dependencies count under the crate they resolve to
+        assert_eq!(
+            extract_dependency_version("[dependencies]\nadk = { package = \"google-adk\", version = \"1.3\" }\n", adk),
+            Some("1.3".to_string())
+        );
+        assert_eq!(
+            extract_dependency_version("[dependencies]\ngoogle-adk = { package = \"adk-fork\", version = \"9.0\" }\n", adk),
+            None
+        );
+
+        // Similarly named crates were matched by substring
+        assert_eq!(
+            extract_dependency_version(
+                "[dependencies]\ngoogle-adk-extras = { version = \"3.0\" }\n",
+                adk
+            ),
+            None
+        );
+
+        // The first listed dependency wins over later names
+        assert_eq!(
+            extract_dependency_version(
+                "[dependencies]\nadk-core = \"0.5\"\ngoogle-adk = \"1.0\"\n",
+                &["google-adk", "adk-core"]
+            ),
+            Some("1.0".to_string())
+        );
+
+        assert_eq!(
+            extract_dependency_version("google-adk = { workspace = true }", adk),
+            None
+        );
+        assert_eq!(extract_dependency_version("tokio = \"1.0\"", adk), None);
+        assert_eq!(
+            extract_dependency_version("{\"google-adk\": \"1.0\"}", adk),
+            None
+        );
+    }
+
+    #[test]
+    fn test_content_contains_token() {
+        assert!(content_contains_token("GOOGLE_API_KEY=abc", "GOOGLE_API_KEY"));
+        assert!(content_contains_token("VERTEXAI_PROJECT=p", "VERTEXAI"));
+        assert!(content_contains_token("google-cloud-aiplatform==1.0", "google-cloud"));
+        assert!(content_contains_token("rmcp = \"0.6\"", "rmcp"));
+        assert!(!content_contains_token("termcpy = \"1\"", "rmcp"));
+        assert!(!content_contains_token("vertexailabs", "vertexai"));
+        assert!(!content_contains_token("PADKEY=1", "ADK"));
+        assert!(content_contains_token("x PADK ADK", "ADK"));
+        assert!(!content_contains_token("anything", ""));
+    }
+}
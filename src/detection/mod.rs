@@ -1,6 +1,9 @@
 pub mod project_detector;
 pub mod file_validator;
 pub mod config_detector;
+pub mod matching;
+pub mod error;
+pub(crate) mod vfs;
 
 #[cfg(test)]
 mod integration_tests;
@@ -10,4 +13,5 @@ mod unit_tests;
 
 pub use project_detector::*;
 pub use file_validator::*;
-pub use config_detector::*;
\ No newline at end of file
+pub use config_detector::*;
+pub use error::*;
\ No newline at end of file
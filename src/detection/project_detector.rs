@@ -1,8 +1,15 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::fs;
+use std::collections::HashMap;
+use std::fmt;
 use std::path::{Path, PathBuf};
 
+use crate::detection::config_detector::McpConfigFile;
+use crate::detection::error::{DetectionError, DetectionResult};
+use crate::detection::file_validator::FileValidator;
+use crate::detection::matching;
+use crate::detection::vfs;
+
 /// Represents the type of ADK project detected
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum AdkProjectType {
@@ -18,6 +25,32 @@ pub enum AdkProjectType {
     None,
 }
 
+/// Why a directory was not classified as an ADK project
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum NoneReason {
+    /// Neither Cargo.toml nor requirements.txt was found
+    NoManifest,
+    /// A manifest exists but declares no ADK dependencies and no ADK config was found
+    NoAdkDependencies,
+    /// ADK dependencies are declared but below the signal threshold, e.g. only as dev
+    /// dependencies while [`AdkProjectDetector::with_consider_dev_deps`] is off
+    BelowSignalThreshold,
+}
+
+/// An explicit `.adk-project` / `.adk.yaml` marker declaring the directory an ADK project
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdkProjectMarker {
+    /// Marker file, relative to the project root
+    pub path: PathBuf,
+    /// Declared project type (`rust-adk`, `python-adk`, `mcp-adk-server`, `mixed`), which
+    /// overrides the heuristic classification
+    pub project_type: Option<AdkProjectType>,
+    /// Declared ADK version
+    pub version: Option<String>,
+    /// Declared owning team or person
+    pub owner: Option<String>,
+}
+
 /// Configuration and metadata for a detected ADK project
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AdkProjectInfo {
@@ -25,19 +58,610 @@ pub struct AdkProjectInfo {
     pub root_path: PathBuf,
     pub has_cargo_toml: bool,
     pub has_requirements_txt: bool,
+    pub has_pyproject_toml: bool,
     pub has_adk_dependencies: bool,
     pub has_adk_config: bool,
     pub estimated_size: u64,
     pub adk_version: Option<String>,
+    /// `adk_version` resolved to a single comparable version; see [`normalize_version_req`]
+    pub normalized_adk_version: Option<semver::Version>,
+    /// Agent-to-Agent (A2A) protocol setup declared by an agent card
+    pub a2a: Option<A2aInfo>,
+    /// Whether Cargo.toml/pyproject.toml parse as TOML (`None` unless the syntax probe is enabled)
+    pub manifest_parses: Option<bool>,
+    /// Whether the entrypoint source passes a cheap tokenizer check (`None` unless the
+    /// syntax probe is enabled and an entrypoint was found)
+    pub entrypoint_parses: Option<bool>,
+    /// Locations of the Cargo.toml, requirements.txt, pyproject.toml and package.json
+    /// manifests found, relative to `root_path`
+    pub manifest_paths: Vec<PathBuf>,
+    /// Why `project_type` is `None`; always `None` for detected projects
+    pub none_reason: Option<NoneReason>,
+    /// Top-level Python package directories (flat or src layout), relative to `root_path`
+    pub python_packages: Vec<PathBuf>,
+    /// Python console entry points (command -> `module:function` target)
+    pub entry_points: HashMap<String, String>,
+    /// Short project description from the manifest, falling back to the README
+    pub description: Option<String>,
+    /// Targets declared by a justfile, Makefile or Taskfile
+    pub task_targets: Vec<TaskTarget>,
+    /// Whether the manifests carry the metadata needed to publish a crate/wheel/sdist
+    pub publishable: bool,
+    /// What is missing before the project can be published
+    pub publish_blockers: Vec<String>,
+    /// Open-source governance files present in the project
+    pub governance: GovernanceInfo,
+    /// Version requirement of the `rmcp` dependency, for `McpAdkServer` projects
+    pub rmcp_version: Option<String>,
+    /// Generated gRPC/protobuf stubs paired with the `.proto` they were generated from,
+    /// both relative to `root_path`
+    pub proto_mappings: Vec<(PathBuf, PathBuf)>,
+    /// Whether a GitHub Actions workflow or Cloud Build config deploys the project
+    pub cd_configured: bool,
+    /// Branch whose pushes trigger the deployment, when declared
+    pub deploy_branch: Option<String>,
+    /// TODO/FIXME/XXX/HACK markers in source files (always 0 unless the debt scan is enabled)
+    pub debt_markers: usize,
+    /// Whether any test file imports ADK modules or patches ADK clients
+    pub tests_cover_adk: bool,
+    /// Whether the Python side is managed by uv (`uv.lock` or a `[tool.uv]` table)
+    pub uses_uv: bool,
+    /// Externalized prompt/instruction files, relative to `root_path`
+    pub prompt_files: Vec<PathBuf>,
+    /// Server transport inferred from dependency features or MCP config
+    pub transport: Option<Transport>,
+    /// Dependencies with wildcard or unbounded versions (empty unless the pin check is enabled)
+    pub loose_versions: Vec<LooseVersion>,
+    /// Session service backend: `database`, `vertex_ai` or `in_memory`
+    pub session_backend: Option<String>,
+    /// Vendored or installed dependencies under a non-permissive license (empty unless the
+    /// license scan is enabled)
+    pub license_warnings: Vec<String>,
+    /// Declared HTTP, database and third-party API clients, sorted
+    pub external_integrations: Vec<String>,
+    /// Whether agent source registers ADK model/tool callbacks or guardrail functions
+    pub has_guardrails: bool,
+    /// Whether agent source defines a health or readiness route such as `/healthz`
+    pub has_health_check: bool,
+    /// Targets declared in Cargo.toml or discovered from the standard layout
+    pub cargo_targets: Vec<CargoTarget>,
+    /// Related Google packages (ADK, GenAI, AI Platform) declared on incompatible major versions
+    pub google_dep_mismatches: Vec<String>,
+    /// Whether the project meets Vertex AI Agent Engine's deployment requirements
+    pub agent_engine_ready: bool,
+    /// What is missing before the project can be deployed to Agent Engine
+    pub agent_engine_blockers: Vec<String>,
+    /// Web framework (e.g. `fastapi`, `axum`) the project declares for serving HTTP
+    pub web_framework: Option<String>,
+    /// Dependencies declared more than once (e.g. in `[dependencies]` and `[dev-dependencies]`)
+    /// with different version requirements
+    pub duplicate_dependencies: Vec<String>,
+    /// Whether `Cargo.lock` or `poetry.lock` is missing a dependency declared in its manifest
+    pub lockfile_stale: bool,
+    /// Declared dependencies absent from the lockfile
+    pub lockfile_missing: Vec<String>,
+    /// Whether dependencies are vendored for offline builds
+    pub vendored: bool,
+    /// Vendored dependency directories, relative to `root_path`; excluded from `estimated_size`
+    pub vendor_dirs: Vec<PathBuf>,
+    /// Declared target platforms: Cargo target keys and metadata targets, Python versions and
+    /// operating systems from classifiers
+    pub supported_platforms: Vec<String>,
+    /// Agent tools mapped to the credentials/config they likely need, inferred from the
+    /// clients they import and the environment variables they read
+    pub tool_requirements: Vec<(String, Vec<String>)>,
+    /// The file and match behind each ADK dependency, config and directory signal
+    pub signal_attribution: Vec<SignalAttribution>,
+    /// Explicit ADK project marker, authoritative over heuristic detection
+    pub marker: Option<AdkProjectMarker>,
+    /// Artifact service backend: `gcs` or `in_memory`
+    pub artifact_backend: Option<String>,
+    /// `google-adk` version installed in the project's virtual environment, which may differ
+    /// from the declared one; only read when enabled with
+    /// [`AdkProjectDetector::with_installed_version_scan`]
+    pub installed_adk_version: Option<String>,
+    /// Files worth processing (see [`AdkProjectDetector::discover_source_files`]); only
+    /// populated when enabled with [`AdkProjectDetector::with_source_files`]
+    pub source_files: Vec<PathBuf>,
+    /// Whether the project lies inside a git submodule of the scanned repository
+    pub in_submodule: bool,
+    /// Submodule containing the project, as declared in `.gitmodules` (relative to the scan root)
+    pub submodule_path: Option<PathBuf>,
+    /// Best-effort purpose (`chatbot`, `rag`, `tool-agent` or `data-pipeline`) guessed from
+    /// dependencies, directory names and README keywords. Heuristic; may be wrong or missing.
+    pub inferred_category: Option<String>,
+}
+
+impl fmt::Display for AdkProjectInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let yes_no = |flag: bool| if flag { "yes" } else { "no" };
+
+        writeln!(f, "ADK project: {:?}", self.project_type)?;
+        if let Some(reason) = &self.none_reason {
+            writeln!(f, "  Reason: {:?}", reason)?;
+        }
+        writeln!(f, "  Root: {}", self.root_path.display())?;
+        writeln!(f, "  ADK version: {}", self.adk_version.as_deref().unwrap_or("unknown"))?;
+        writeln!(
+            f,
+            "  Cargo.toml: {}, requirements.txt: {}, pyproject.toml: {}",
+            yes_no(self.has_cargo_toml),
+            yes_no(self.has_requirements_txt),
+            yes_no(self.has_pyproject_toml)
+        )?;
+        writeln!(
+            f,
+            "  ADK dependencies: {}, ADK config: {}",
+            yes_no(self.has_adk_dependencies),
+            yes_no(self.has_adk_config)
+        )?;
+        if let Some(a2a) = &self.a2a {
+            writeln!(f, "  A2A skills: {}", a2a.skills.len())?;
+        }
+        if let Some(parses) = self.entrypoint_parses {
+            writeln!(f, "  Entrypoint parses: {}", yes_no(parses))?;
+        }
+        write!(f, "  Estimated size: {}", FileValidator::format_file_size(self.estimated_size))
+    }
+}
+
+/// Upper bounds of the `estimated_size` buckets; two scans in the same bucket are the same size
+const SIZE_BUCKETS: &[u64] =
+    &[10 * 1024, 100 * 1024, 1024 * 1024, 10 * 1024 * 1024, 100 * 1024 * 1024];
+
+impl AdkProjectInfo {
+    /// Whether the project was classified as an ADK project of any kind
+    pub fn is_adk(&self) -> bool {
+        self.project_type != AdkProjectType::None
+    }
+
+    /// Whether the scan found nothing at all: no manifests, ADK dependencies or ADK config
+    pub fn is_empty(&self) -> bool {
+        !self.is_adk()
+            && !self.has_cargo_toml
+            && !self.has_requirements_txt
+            && !self.has_pyproject_toml
+            && !self.uses_uv
+            && !self.has_adk_dependencies
+            && !self.has_adk_config
+            && self.manifest_paths.is_empty()
+    }
+
+    /// Path of the manifest that drove the classification, chosen from `manifest_paths`:
+    ///
+    /// - Rust and MCP server projects: Cargo.toml
+    /// - Python projects: pyproject.toml, then requirements.txt
+    /// - Mixed projects: the first manifest declaring an ADK dependency, in the order
+    ///   Cargo.toml, pyproject.toml, requirements.txt
+    ///
+    /// Otherwise (e.g. config-only detection) the first manifest in the order Cargo.toml,
+    /// pyproject.toml, requirements.txt, package.json is used.
+    pub fn primary_manifest(&self) -> Option<PathBuf> {
+        const PRECEDENCE: &[&str] = &["Cargo.toml", "pyproject.toml", "requirements.txt", "package.json"];
+
+        let declares_adk = |manifest: &PathBuf| {
+            self.signal_attribution
+                .iter()
+                .any(|signal| signal.signal == "dependency" && &signal.file == manifest)
+        };
+        let first = |names: &[&str], filter: &dyn Fn(&PathBuf) -> bool| {
+            names.iter().find_map(|name| {
+                self.manifest_paths
+                    .iter()
+                    .find(|manifest| manifest.ends_with(name) && filter(manifest))
+            })
+        };
+
+        let preferred = match self.project_type {
+            AdkProjectType::None => return None,
+            AdkProjectType::RustAdk | AdkProjectType::McpAdkServer => first(&["Cargo.toml"], &|_| true),
+            AdkProjectType::PythonAdk => first(&["pyproject.toml", "requirements.txt"], &|_| true),
+            AdkProjectType::Mixed => first(PRECEDENCE, &declares_adk),
+        };
+        preferred
+            .or_else(|| first(PRECEDENCE, &|_| true))
+            .map(|manifest| self.root_path.join(manifest))
+    }
+
+    /// Compare this scan against a later one, reporting meaningful changes only.
+    /// Size changes are reported only when the size moves to another of [`SIZE_BUCKETS`].
+    pub fn diff(&self, other: &AdkProjectInfo) -> ProjectDiff {
+        let mut diff = ProjectDiff::default();
+        let mut record = |field: &str, before: String, after: String| {
+            if before != after {
+                diff.changes.push(FieldChange {
+                    field: field.to_string(),
+                    before,
+                    after,
+                });
+            }
+        };
+        let version = |info: &AdkProjectInfo| info.adk_version.clone().unwrap_or_else(|| "none".to_string());
+        let rmcp_version =
+            |info: &AdkProjectInfo| info.rmcp_version.clone().unwrap_or_else(|| "none".to_string());
+        let transport = |info: &AdkProjectInfo| {
+            info.transport.as_ref().map_or_else(|| "none".to_string(), |t| format!("{:?}", t))
+        };
+
+        record(
+            "project_type",
+            format!("{:?}", self.project_type),
+            format!("{:?}", other.project_type),
+        );
+        record("adk_version", version(self), version(other));
+        record(
+            "has_adk_dependencies",
+            self.has_adk_dependencies.to_string(),
+            other.has_adk_dependencies.to_string(),
+        );
+        record(
+            "adk_dependencies",
+            self.signal_summary("dependency"),
+            other.signal_summary("dependency"),
+        );
+        record("has_adk_config", self.has_adk_config.to_string(), other.has_adk_config.to_string());
+        record("adk_config", self.signal_summary("config"), other.signal_summary("config"));
+        record("rmcp_version", rmcp_version(self), rmcp_version(other));
+        record("transport", transport(self), transport(other));
+        record("has_cargo_toml", self.has_cargo_toml.to_string(), other.has_cargo_toml.to_string());
+        record(
+            "has_requirements_txt",
+            self.has_requirements_txt.to_string(),
+            other.has_requirements_txt.to_string(),
+        );
+        record(
+            "has_pyproject_toml",
+            self.has_pyproject_toml.to_string(),
+            other.has_pyproject_toml.to_string(),
+        );
+
+        let size_bucket =
+            |size: u64| SIZE_BUCKETS.iter().take_while(|&&bound| size >= bound).count();
+        if size_bucket(self.estimated_size) != size_bucket(other.estimated_size) {
+            record(
+                "estimated_size",
+                FileValidator::format_file_size(self.estimated_size),
+                FileValidator::format_file_size(other.estimated_size),
+            );
+        }
+
+        diff
+    }
+
+    /// Sorted, comma-separated `signal` attributions (`matched` for dependencies, otherwise
+    /// `file: matched`), or `none`
+    fn signal_summary(&self, signal: &str) -> String {
+        let mut entries: Vec<String> = self
+            .signal_attribution
+            .iter()
+            .filter(|attribution| attribution.signal == signal)
+            .map(|attribution| match signal {
+                "dependency" => attribution.matched.clone(),
+                _ => format!("{}: {}", attribution.file.display(), attribution.matched),
+            })
+            .collect();
+        entries.sort();
+        entries.dedup();
+        if entries.is_empty() {
+            "none".to_string()
+        } else {
+            entries.join(", ")
+        }
+    }
+}
+
+/// Meaningful changes between two scans of the same project
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProjectDiff {
+    pub changes: Vec<FieldChange>,
+}
+
+impl ProjectDiff {
+    /// Whether the two scans are equivalent
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Get the change recorded for a field, if any
+    pub fn change(&self, field: &str) -> Option<&FieldChange> {
+        self.changes.iter().find(|change| change.field == field)
+    }
+}
+
+/// A single field that differs between two project scans
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// Governance files found in the root, `.github/` or `docs/` (paths relative to the root)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GovernanceInfo {
+    pub contributing: Option<PathBuf>,
+    pub codeowners: Option<PathBuf>,
+    pub security_policy: Option<PathBuf>,
+    pub code_of_conduct: Option<PathBuf>,
+}
+
+/// Result of a name-only [`AdkProjectDetector::quick_probe`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum QuickProbe {
+    /// Marker files exist (paths relative to the probed directory); run full detection to confirm
+    Maybe(Vec<PathBuf>),
+    /// No marker files, so the directory cannot be an ADK project
+    No,
+}
+
+impl QuickProbe {
+    /// Whether the directory is worth a full detection pass
+    pub fn is_maybe(&self) -> bool {
+        matches!(self, QuickProbe::Maybe(_))
+    }
+}
+
+/// One piece of evidence behind an ADK classification
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignalAttribution {
+    /// Kind of signal: `dependency`, `config` or `directory`
+    pub signal: String,
+    /// File or directory that produced the signal, relative to the project root
+    pub file: PathBuf,
+    /// Dependency name, config marker or directory name that matched
+    pub matched: String,
+}
+
+/// Which dependencies the opt-in version pinning check inspects
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PinCheck {
+    /// No check (the default)
+    #[default]
+    Off,
+    /// Only known ADK dependencies
+    Adk,
+    /// Every declared dependency
+    All,
+}
+
+/// A dependency declared with a wildcard or unbounded version requirement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LooseVersion {
+    pub dependency: String,
+    /// The requirement as written, empty when no version was given
+    pub requirement: String,
+    /// Manifest declaring the dependency, relative to the project root
+    pub manifest: PathBuf,
+}
+
+/// Transport an ADK or MCP server uses to talk to its clients
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Transport {
+    /// Standard input/output (e.g. rmcp `transport-io`)
+    Stdio,
+    /// Server-sent events
+    Sse,
+    /// WebSocket streaming
+    WebSocket,
+    /// Plain or streamable HTTP
+    Http,
+}
+
+/// Kind of a Cargo build target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CargoTargetKind {
+    Lib,
+    Bin,
+    Example,
+}
+
+/// A library, binary or example target of a Rust crate
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CargoTarget {
+    pub name: String,
+    pub kind: CargoTargetKind,
+    /// Source file of the target, relative to the project root
+    pub path: PathBuf,
+}
+
+/// A task runner target (justfile recipe, Makefile target or Taskfile task)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskTarget {
+    pub name: String,
+    /// Task runner file declaring the target, relative to the project root
+    pub source: PathBuf,
+    /// Whether the name or recipe suggests an ADK workflow (deploy, eval, serving an agent)
+    pub looks_adk: bool,
+}
+
+/// Agent-to-Agent (A2A) agent card information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct A2aInfo {
+    pub card_path: PathBuf,
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub url: Option<String>,
+    /// Skill ids (or names when no id is given) declared by the card
+    pub skills: Vec<String>,
+    /// Capabilities enabled by the card (e.g. `streaming`)
+    pub capabilities: Vec<String>,
+}
+
+/// A package name and the major version it is declared on
+type MajorVersion = (&'static str, u64);
+
+/// Client modules and the configuration their users usually need
+const TOOL_CLIENT_REQUIREMENTS: &[(&str, &[&str])] = &[
+    ("openai", &["OPENAI_API_KEY"]),
+    ("anthropic", &["ANTHROPIC_API_KEY"]),
+    ("boto3", &["AWS_ACCESS_KEY_ID", "AWS_SECRET_ACCESS_KEY"]),
+    ("stripe", &["STRIPE_API_KEY"]),
+    ("slack_sdk", &["SLACK_BOT_TOKEN"]),
+    ("twilio", &["TWILIO_ACCOUNT_SID", "TWILIO_AUTH_TOKEN"]),
+    ("github", &["GITHUB_TOKEN"]),
+    ("google.cloud", &["GOOGLE_CLOUD_PROJECT", "GOOGLE_APPLICATION_CREDENTIALS"]),
+    ("psycopg", &["DATABASE_URL"]),
+    ("psycopg2", &["DATABASE_URL"]),
+    ("sqlalchemy", &["DATABASE_URL"]),
+    ("redis", &["REDIS_URL"]),
+    ("pymongo", &["MONGODB_URI"]),
+];
+
+/// Web frameworks that expose an agent over HTTP/REST, in detection priority order
+const WEB_FRAMEWORKS: &[&str] = &["fastapi", "flask", "django", "axum", "actix-web", "rocket", "warp"];
+
+/// Signals that suggest a project category, weighted by [`AdkProjectDetector::infer_category`]
+struct CategorySignals {
+    category: &'static str,
+    dependencies: &'static [&'static str],
+    directories: &'static [&'static str],
+    readme_keywords: &'static [&'static str],
+}
+
+/// Project categories for catalog tagging; ties go to the earlier entry
+const CATEGORY_SIGNALS: &[CategorySignals] = &[
+    CategorySignals {
+        category: "rag",
+        dependencies: &[
+            "chromadb", "pinecone", "pinecone-client", "qdrant-client", "weaviate-client", "faiss-cpu",
+            "faiss-gpu", "pgvector", "llama-index", "google-cloud-discoveryengine",
+        ],
+        directories: &["rag", "retrieval", "embeddings", "vectorstore", "corpus"],
+        readme_keywords: &["retrieval", "rag", "vector", "embedding", "embeddings", "knowledge base"],
+    },
+    CategorySignals {
+        category: "data-pipeline",
+        dependencies: &["apache-beam", "pandas", "polars", "pyspark", "google-cloud-bigquery", "dbt-core"],
+        directories: &["pipelines", "pipeline", "etl", "dags"],
+        readme_keywords: &["pipeline", "etl", "ingestion", "bigquery", "batch"],
+    },
+    CategorySignals {
+        category: "chatbot",
+        dependencies: &["gradio", "streamlit", "chainlit", "slack-bolt", "discord.py"],
+        directories: &["chat", "conversations"],
+        readme_keywords: &["chatbot", "chat", "conversation", "conversational", "customer support"],
+    },
+    CategorySignals {
+        category: "tool-agent",
+        dependencies: &["mcp", "rmcp", "google-api-python-client"],
+        directories: &["tools"],
+        readme_keywords: &["tool", "tools", "function calling", "toolset"],
+    },
+];
+
+/// The files of a project, listed by one walk per detection and shared by the size estimate,
+/// source file discovery and the analyzers that scan sources
+struct ProjectFiles {
+    /// Every file outside skipped and vendored directories, relative to the root and sorted,
+    /// with its size in bytes
+    files: Vec<(PathBuf, u64)>,
+    /// Text of the Rust/Python sources, `.env` files and JSON/YAML/TOML configs at the root,
+    /// in [`SOURCE_SCAN_DIRS`], in an agent directory (one with an `agent.py`) or in a Python
+    /// package, read in path order until the source scan budget is spent
+    contents: Vec<(PathBuf, String)>,
+}
+
+impl ProjectFiles {
+    /// Extensions of the files whose contents are read
+    const READ_EXTENSIONS: &'static [&'static str] = &["rs", "py", "json", "yaml", "yml", "toml"];
+
+    fn paths(&self) -> impl Iterator<Item = &PathBuf> {
+        self.files.iter().map(|(file, _)| file)
+    }
+
+    fn is_env_file(file: &Path) -> bool {
+        file.file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|name| name.starts_with(".env"))
+    }
+
+    fn has_extension(file: &Path, extensions: &[&str]) -> bool {
+        file.extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| extensions.contains(&ext))
+    }
+
+    /// Contents of the read files with one of `extensions`
+    fn with_extensions<'a>(
+        &'a self,
+        extensions: &'a [&str],
+    ) -> impl Iterator<Item = &'a (PathBuf, String)> {
+        self.contents
+            .iter()
+            .filter(move |(file, _)| Self::has_extension(file, extensions))
+    }
 }
 
+/// ADK package and major version, with the major version each related Google package must
+/// be on to work with it
+const GOOGLE_DEP_COMPAT: &[(&str, u64, &[MajorVersion])] = &[(
+    "google-adk",
+    1,
+    &[("google-genai", 1), ("google-cloud-aiplatform", 1)],
+)];
+
+/// Dependency names of HTTP, database and third-party API clients
+const INTEGRATION_DEPENDENCIES: &[&str] = &[
+    // Rust HTTP clients
+    "reqwest", "hyper", "ureq", "surf", "isahc",
+    // Rust database drivers and ORMs
+    "sqlx", "diesel", "sea-orm", "tokio-postgres", "postgres", "mysql_async", "rusqlite", "redis",
+    "mongodb",
+    // Python HTTP clients
+    "requests", "httpx", "aiohttp", "urllib3",
+    // Python database drivers and ORMs
+    "sqlalchemy", "psycopg", "psycopg2", "psycopg2-binary", "asyncpg", "pymysql", "pymongo", "motor",
+    // Third-party service SDKs
+    "boto3", "openai", "anthropic", "stripe", "twilio", "slack-sdk",
+];
+
+/// Top-level directories, besides the root itself and Python packages, whose sources the
+/// content analyzers read: agent code, configuration and tests
+const SOURCE_SCAN_DIRS: &[&str] = &[
+    "src", "app", "agents", "adk_agents", "multi_tool_agent", "config", "tests", "test",
+];
+
+/// Build, cache and vendored-dependency directory names skipped by the project walkers unless
+/// configured otherwise
+pub const DEFAULT_SKIP_DIRS: &[&str] =
+    &["target", "node_modules", ".git", "__pycache__", ".venv", "vendor"];
+
 /// Main project detector for ADK projects
+#[derive(Debug, Clone)]
 pub struct AdkProjectDetector {
     /// Maximum file size to analyze (in bytes)
     max_file_size: u64,
     /// Known ADK dependency patterns
     adk_rust_dependencies: Vec<String>,
     adk_python_dependencies: Vec<String>,
+    /// Whether to run the lightweight manifest/entrypoint syntax probe
+    syntax_probe: bool,
+    /// How many directory levels below the root to search for a missing manifest
+    manifest_search_depth: usize,
+    /// Directory names skipped when walking the project (build artifacts, caches)
+    skip_dirs: Vec<String>,
+    /// Oldest `rmcp` version MCP servers are expected to depend on
+    min_rmcp_version: String,
+    /// Maximum directory depth searched by `find_adk_projects`
+    max_depth: usize,
+    /// Whether to count TODO/FIXME markers in source files
+    debt_scan: bool,
+    /// Total bytes of sources the content analyzers read per detection
+    source_scan_budget: u64,
+    /// Which dependency version requirements to check for pinning
+    pin_check: PinCheck,
+    /// Whether to read license metadata of vendored/installed dependencies
+    license_scan: bool,
+    /// Whether dev-only dependencies count towards ADK classification
+    consider_dev_deps: bool,
+    /// Whether `find_adk_projects` skips git submodules declared in `.gitmodules`
+    skip_submodules: bool,
+    /// Whether detection lists the project's source files in `source_files`
+    collect_source_files: bool,
+    /// Whether to read the ADK version installed in the project's virtual environment
+    installed_version_scan: bool,
+    /// Whether an unreadable or malformed A2A agent card fails detection
+    strict_parsing: bool,
 }
 
 impl Default for AdkProjectDetector {
@@ -61,6 +685,20 @@ impl Default for AdkProjectDetector {
                 "google-cloud-aiplatform".to_string(),
                 "adk-agents".to_string(),
             ],
+            syntax_probe: false,
+            manifest_search_depth: 0,
+            skip_dirs: DEFAULT_SKIP_DIRS.iter().map(|dir| dir.to_string()).collect(),
+            min_rmcp_version: "0.6.0".to_string(),
+            max_depth: 3,
+            debt_scan: false,
+            source_scan_budget: 8 * 1024 * 1024, // 8MB default budget
+            pin_check: PinCheck::Off,
+            license_scan: false,
+            consider_dev_deps: true,
+            skip_submodules: false,
+            collect_source_files: false,
+            installed_version_scan: false,
+            strict_parsing: false,
         }
     }
 }
@@ -74,402 +712,5334 @@ impl AdkProjectDetector {
         }
     }
 
+    /// Create a detector using the file size limit, search depth and skip directories of a
+    /// [`DetectionConfig`]
+    ///
+    /// [`DetectionConfig`]: crate::DetectionConfig
+    pub fn from_config(config: &crate::DetectionConfig) -> Self {
+        Self::default().with_config(config)
+    }
+
+    /// Copy of this detector with the settings of `config` applied on top
+    fn with_config(&self, config: &crate::DetectionConfig) -> Self {
+        Self {
+            max_file_size: config.max_file_size,
+            skip_dirs: config.skip_dirs.clone(),
+            max_depth: config.max_depth,
+            ..self.clone()
+        }
+    }
+
+    /// Check whether a directory name is in the configured skip set
+    fn is_skipped_dir(&self, name: &str) -> bool {
+        self.skip_dirs.iter().any(|dir| dir == name)
+    }
+
+    /// Enable the syntax probe, which checks that the manifest parses as TOML and that the
+    /// entrypoint tokenizes cleanly. This is a heuristic for quick health gating, not a
+    /// compiler: it catches unbalanced delimiters and unterminated strings or comments only.
+    pub fn with_syntax_probe(mut self, enabled: bool) -> Self {
+        self.syntax_probe = enabled;
+        self
+    }
+
+    /// Search up to `depth` levels below the root for a Cargo.toml or requirements.txt that the
+    /// root itself lacks (e.g. `rust/Cargo.toml` next to a top-level Python project)
+    pub fn with_manifest_search_depth(mut self, depth: usize) -> Self {
+        self.manifest_search_depth = depth;
+        self
+    }
+
+    /// Count TODO/FIXME/XXX/HACK markers across Rust and Python sources as a review-priority
+    /// signal. This reads every source file, so it is off by default.
+    pub fn with_debt_scan(mut self, enabled: bool) -> Self {
+        self.debt_scan = enabled;
+        self
+    }
+
+    /// Stop reading sources for the content analyzers (tools, guardrails, health check,
+    /// backends, prompts, test coverage, Agent Engine readiness) once `bytes` have been read.
+    /// Only the root, agent, config and test directories and Python packages are scanned.
+    pub fn with_source_scan_budget(mut self, bytes: u64) -> Self {
+        self.source_scan_budget = bytes;
+        self
+    }
+
+    /// Flag wildcard (`*`, `1.*`) and lower-bound-only (`>=1.0`) version requirements, or
+    /// Python requirements with no version at all, for ADK or all dependencies
+    pub fn with_pin_check(mut self, pin_check: PinCheck) -> Self {
+        self.pin_check = pin_check;
+        self
+    }
+
+    /// Flag dependencies whose license metadata is available locally (`cargo vendor` output in
+    /// `vendor/`, or `.dist-info` metadata in a `.venv`) and is not permissive. Best-effort:
+    /// dependencies without local metadata are not checked.
+    pub fn with_license_scan(mut self, enabled: bool) -> Self {
+        self.license_scan = enabled;
+        self
+    }
+
+    /// Whether `[dev-dependencies]` and pyproject `[dependency-groups]` count towards ADK
+    /// classification (the default). Disable to classify by runtime dependencies only, so a
+    /// test harness pulling in ADK does not make a project an ADK project.
+    pub fn with_consider_dev_deps(mut self, enabled: bool) -> Self {
+        self.consider_dev_deps = enabled;
+        self
+    }
+
+    /// Read the `google-adk` version actually installed in the project's `.venv`/`venv` from
+    /// its `.dist-info` directory into `installed_adk_version`
+    pub fn with_installed_version_scan(mut self, enabled: bool) -> Self {
+        self.installed_version_scan = enabled;
+        self
+    }
+
+    /// List the files [`Self::discover_source_files`] finds in `source_files` during detection
+    pub fn with_source_files(mut self, enabled: bool) -> Self {
+        self.collect_source_files = enabled;
+        self
+    }
+
+    /// Skip git submodules (declared in the scan root's `.gitmodules`) in `find_adk_projects`,
+    /// so vendored ADK projects are not reported alongside the parent repository
+    pub fn with_skip_submodules(mut self, enabled: bool) -> Self {
+        self.skip_submodules = enabled;
+        self
+    }
+
+    /// Fail detection with [`DetectionError::UnreadableConfig`] or [`DetectionError::ParseError`]
+    /// when an A2A agent card cannot be read or parsed, instead of ignoring the card
+    pub fn with_strict_parsing(mut self, enabled: bool) -> Self {
+        self.strict_parsing = enabled;
+        self
+    }
+
+    /// Set the oldest `rmcp` version MCP servers may depend on before an upgrade is recommended
+    pub fn with_min_rmcp_version(mut self, version: &str) -> Self {
+        self.min_rmcp_version = version.to_string();
+        self
+    }
+
+    /// Get the Rust dependency names treated as ADK indicators
+    pub fn known_rust_dependencies(&self) -> &[String] {
+        &self.adk_rust_dependencies
+    }
+
+    /// Get the Python dependency names treated as ADK indicators
+    pub fn known_python_dependencies(&self) -> &[String] {
+        &self.adk_python_dependencies
+    }
+
     /// Detect if a directory contains an ADK project
-    pub fn detect_adk_project<P: AsRef<Path>>(&self, path: P) -> Result<AdkProjectInfo> {
+    pub fn detect_adk_project<P: AsRef<Path>>(&self, path: P) -> DetectionResult<AdkProjectInfo> {
         let path = path.as_ref();
+        if !vfs::exists(path) {
+            return Err(DetectionError::PathNotFound(path.to_path_buf()));
+        }
         let mut project_info = AdkProjectInfo {
             project_type: AdkProjectType::None,
             root_path: path.to_path_buf(),
             has_cargo_toml: false,
             has_requirements_txt: false,
+            has_pyproject_toml: false,
             has_adk_dependencies: false,
             has_adk_config: false,
             estimated_size: 0,
             adk_version: None,
+            normalized_adk_version: None,
+            a2a: None,
+            manifest_parses: None,
+            entrypoint_parses: None,
+            manifest_paths: Vec::new(),
+            none_reason: None,
+            python_packages: Vec::new(),
+            entry_points: HashMap::new(),
+            description: None,
+            task_targets: Vec::new(),
+            publishable: false,
+            publish_blockers: Vec::new(),
+            governance: GovernanceInfo::default(),
+            rmcp_version: None,
+            proto_mappings: Vec::new(),
+            cd_configured: false,
+            deploy_branch: None,
+            debt_markers: 0,
+            tests_cover_adk: false,
+            uses_uv: false,
+            prompt_files: Vec::new(),
+            transport: None,
+            loose_versions: Vec::new(),
+            session_backend: None,
+            license_warnings: Vec::new(),
+            external_integrations: Vec::new(),
+            has_guardrails: false,
+            has_health_check: false,
+            cargo_targets: Vec::new(),
+            google_dep_mismatches: Vec::new(),
+            agent_engine_ready: false,
+            agent_engine_blockers: Vec::new(),
+            web_framework: None,
+            duplicate_dependencies: Vec::new(),
+            lockfile_stale: false,
+            lockfile_missing: Vec::new(),
+            vendored: false,
+            vendor_dirs: Vec::new(),
+            supported_platforms: Vec::new(),
+            tool_requirements: Vec::new(),
+            signal_attribution: Vec::new(),
+            marker: None,
+            artifact_backend: None,
+            installed_adk_version: None,
+            source_files: Vec::new(),
+            in_submodule: false,
+            submodule_path: None,
+            inferred_category: None,
         };
+        let mut rmcp_version = None;
 
         // Check for Cargo.toml (Rust project)
-        let cargo_path = path.join("Cargo.toml");
-        if cargo_path.exists() {
+        if let Some(cargo_path) = self.locate_manifest(path, "Cargo.toml") {
             project_info.has_cargo_toml = true;
-            if let Ok(cargo_content) = fs::read_to_string(&cargo_path) {
-                project_info.has_adk_dependencies =
-                    self.check_rust_adk_dependencies(&cargo_content);
+            if let Ok(cargo_content) = vfs::read_to_string(path.join(&cargo_path)) {
+                let matched = self.detect_rust_adk_dependencies(&cargo_content);
+                project_info.has_adk_dependencies = !matched.is_empty();
+                project_info
+                    .signal_attribution
+                    .extend(Self::attribute("dependency", &cargo_path, matched));
                 project_info.adk_version = self.extract_adk_version_from_cargo(&cargo_content);
+                rmcp_version = matching::extract_dependency_version(&cargo_content, &["rmcp"]);
             }
+            project_info.cargo_targets = self.detect_cargo_targets(path, &cargo_path);
+            project_info.manifest_paths.push(cargo_path);
         }
 
-        // Check for requirements.txt or setup.py (Python project)
-        let requirements_path = path.join("requirements.txt");
-        let setup_py_path = path.join("setup.py");
-        if requirements_path.exists() || setup_py_path.exists() {
-            project_info.has_requirements_txt = requirements_path.exists();
-
-            if requirements_path.exists() {
-                if let Ok(req_content) = fs::read_to_string(&requirements_path) {
-                    if self.check_python_adk_dependencies(&req_content) {
-                        project_info.has_adk_dependencies = true;
-                    }
+        // Check for requirements.txt (Python project)
+        if let Some(requirements_path) = self.locate_manifest(path, "requirements.txt") {
+            project_info.has_requirements_txt = true;
+            if let Ok(req_content) = vfs::read_to_string(path.join(&requirements_path)) {
+                let matched = self.matched_python_adk_dependencies(&req_content);
+                if !matched.is_empty() {
+                    project_info.has_adk_dependencies = true;
                 }
+                project_info
+                    .signal_attribution
+                    .extend(Self::attribute("dependency", &requirements_path, matched));
+            }
+            project_info.manifest_paths.push(requirements_path);
+        }
+
+        // Check for pyproject.toml (PEP 621, Poetry or uv Python project)
+        if vfs::is_file(path.join("pyproject.toml")) {
+            project_info.has_pyproject_toml = true;
+            let matched = self.matched_pyproject_adk_dependencies(path);
+            if !matched.is_empty() {
+                project_info.has_adk_dependencies = true;
+            }
+            project_info
+                .signal_attribution
+                .extend(Self::attribute("dependency", Path::new("pyproject.toml"), matched));
+            project_info.manifest_paths.push(PathBuf::from("pyproject.toml"));
+        }
+
+        // Record a package.json (e.g. a TypeScript agent or web frontend); it is not an ADK signal
+        if let Some(package_json_path) = self.locate_manifest(path, "package.json") {
+            project_info.manifest_paths.push(package_json_path);
+        }
+
+        // For uv-managed projects, use uv.lock for the resolved ADK version
+        if self.is_uv_project(path) {
+            project_info.uses_uv = true;
+            if project_info.adk_version.is_none() {
+                project_info.adk_version = self.extract_adk_version_from_uv_lock(path);
+            }
+        }
+        project_info.normalized_adk_version =
+            project_info.adk_version.as_deref().and_then(normalize_version_req);
+
+        // Optionally read the ADK version installed in the virtual environment
+        if self.installed_version_scan {
+            project_info.installed_adk_version = Self::installed_package_version(path, "google-adk");
+        }
+
+        // Locate Python package directories
+        project_info.python_packages = self.find_python_packages(path);
+
+        // Extract console entry points declared by Python packaging metadata
+        project_info.entry_points = self.extract_python_entry_points(path);
+
+        // Describe the project from its manifest, or failing that its README
+        project_info.description = self
+            .extract_manifest_description(path, &project_info.manifest_paths)
+            .or_else(|| self.extract_readme_description(path));
+
+        // Collect task runner targets
+        project_info.task_targets = self.detect_task_targets(path);
+
+        // Check packaging metadata needed for publishing
+        project_info.publish_blockers = self.find_publish_blockers(path, &project_info.manifest_paths);
+        project_info.publishable = project_info.publish_blockers.is_empty();
+
+        // Check for governance files
+        project_info.governance = self.detect_governance(path);
+
+        // Find vendored dependency directories, which the walk below skips
+        project_info.vendor_dirs = self.find_vendor_dirs(path);
+        project_info.vendored = !project_info.vendor_dirs.is_empty();
+
+        // Walk the project once and read its agent sources for the analyzers below
+        let files = self.read_project_files(path, &project_info)?;
+
+        // Link generated protobuf stubs to their source .proto files
+        project_info.proto_mappings = self.find_proto_mappings(&files);
+
+        // Check for continuous deployment tied to a branch
+        let (cd_configured, deploy_branch) = self.detect_continuous_deployment(path);
+        project_info.cd_configured = cd_configured;
+        project_info.deploy_branch = deploy_branch;
+
+        // Check for an A2A agent card
+        project_info.a2a = self.detect_a2a_card(path)?;
+
+        // Check for an explicit ADK project marker
+        project_info.marker = self.read_project_marker(path);
+        if let Some(marker) = &project_info.marker {
+            if project_info.adk_version.is_none() {
+                project_info.adk_version = marker.version.clone();
+                project_info.normalized_adk_version =
+                    marker.version.as_deref().and_then(normalize_version_req);
             }
+            project_info.signal_attribution.push(SignalAttribution {
+                signal: "marker".to_string(),
+                file: marker.path.clone(),
+                matched: marker.path.display().to_string(),
+            });
         }
 
         // Check for ADK-specific configuration files
-        project_info.has_adk_config = self.check_adk_config_files(path)?;
+        let config_signals = self.find_adk_config_signals(path);
+        project_info.has_adk_config = !config_signals.is_empty();
+        project_info.signal_attribution.extend(config_signals);
+
+        // Optionally check that the manifest and entrypoint at least parse
+        if self.syntax_probe {
+            project_info.manifest_parses = self.probe_manifests(path);
+            project_info.entrypoint_parses = self.probe_entrypoint(path);
+        }
+
+        // Optionally flag loosely versioned dependencies
+        if self.pin_check != PinCheck::Off {
+            project_info.loose_versions =
+                self.find_loose_versions(path, &project_info.manifest_paths);
+        }
+
+        // Check for dependencies declared more than once with different versions
+        project_info.duplicate_dependencies =
+            self.find_duplicate_dependencies(path, &project_info.manifest_paths);
+
+        // Check lockfiles for dependencies added to the manifest since they were generated
+        project_info.lockfile_missing = Self::find_lockfile_missing(path);
+        project_info.lockfile_stale = !project_info.lockfile_missing.is_empty();
+
+        // Check related Google packages for incompatible major versions
+        project_info.google_dep_mismatches =
+            self.find_google_dep_mismatches(path, &project_info.manifest_paths);
+
+        // Optionally check dependency licenses
+        if self.license_scan {
+            project_info.license_warnings = self.find_license_warnings(path);
+        }
+
+        // Map declared clients for other APIs and databases
+        project_info.external_integrations =
+            self.find_external_integrations(path, &project_info.manifest_paths);
+
+        // Check for a web framework exposing an HTTP surface
+        project_info.web_framework = self.detect_web_framework(path, &project_info.manifest_paths);
+
+        // Guess the project's purpose for catalog tagging
+        project_info.inferred_category = self.infer_category(path, &project_info.manifest_paths);
+
+        // Map agent tools to the credentials they need
+        project_info.tool_requirements = self.find_tool_requirements(&files);
+
+        // Check for callback-based guardrails
+        project_info.has_guardrails = self.detect_guardrails(&files);
+        project_info.has_health_check = self.detect_health_check(&files);
+
+        // Identify the session and artifact service backends
+        project_info.session_backend = self.detect_session_backend(&files);
+        project_info.artifact_backend = self.detect_artifact_backend(&files);
+
+        // Infer the server transport
+        project_info.transport = self.detect_transport(path, &project_info.manifest_paths);
+
+        // Find externalized prompt files
+        project_info.prompt_files = self.find_prompt_files(&files);
+
+        // Check whether tests reference ADK code
+        project_info.tests_cover_adk = self.tests_reference_adk(&files);
+
+        // Optionally count technical debt markers
+        if self.debt_scan {
+            project_info.debt_markers = self.count_debt_markers(path, &files);
+        }
+
+        // Collect declared OS/architecture support
+        project_info.supported_platforms =
+            self.find_supported_platforms(path, &project_info.manifest_paths);
 
         // Estimate project size
-        project_info.estimated_size = self.estimate_project_size(path)?;
+        project_info.estimated_size = files.files.iter().map(|(_, size)| size).sum();
+        if self.collect_source_files {
+            project_info.source_files = self.discover_source_files_in(path, &files.files);
+        }
 
         // Determine project type based on findings
-        project_info.project_type = self.determine_project_type(&project_info);
+        let (project_type, none_reason) = self.determine_project_type(&project_info);
+        project_info.project_type = project_type;
+        project_info.none_reason = none_reason;
+        if project_info.project_type == AdkProjectType::McpAdkServer {
+            project_info.rmcp_version = rmcp_version;
+        }
+
+        // Check Agent Engine deployment requirements
+        project_info.agent_engine_blockers =
+            self.find_agent_engine_blockers(path, &project_info, &files);
+        project_info.agent_engine_ready = project_info.agent_engine_blockers.is_empty();
 
         Ok(project_info)
     }
 
-    /// Check if Cargo.toml contains ADK-related dependencies
-    fn check_rust_adk_dependencies(&self, cargo_content: &str) -> bool {
-        for dep in &self.adk_rust_dependencies {
-            if cargo_content.contains(dep) {
-                return true;
-            }
-        }
-        false
+    /// Async version of [`Self::detect_adk_project`] with identical results. Detection still does
+    /// blocking `std::fs` I/O; it runs on tokio's blocking thread pool via `spawn_blocking`,
+    /// so it needs a tokio runtime and occupies a blocking-pool thread while it runs.
+    #[cfg(feature = "tokio")]
+    pub async fn detect_adk_project_async<P: AsRef<Path>>(&self, path: P) -> DetectionResult<AdkProjectInfo> {
+        let detector = self.clone();
+        let path = path.as_ref().to_path_buf();
+        tokio::task::spawn_blocking(move || detector.detect_adk_project(path)).await?
     }
 
-    /// Check if requirements.txt contains ADK-related dependencies
-    fn check_python_adk_dependencies(&self, requirements_content: &str) -> bool {
-        for dep in &self.adk_python_dependencies {
-            if requirements_content.contains(dep) {
-                return true;
-            }
-        }
-        false
+    /// Detect an ADK project using `config` for this call only; the detector is left unchanged
+    pub fn detect_adk_project_with<P: AsRef<Path>>(
+        &self,
+        path: P,
+        config: &crate::DetectionConfig,
+    ) -> DetectionResult<AdkProjectInfo> {
+        self.with_config(config).detect_adk_project(path)
     }
 
-    /// Extract ADK version from Cargo.toml if available
-    fn extract_adk_version_from_cargo(&self, cargo_content: &str) -> Option<String> {
-        // Look for version patterns in ADK dependencies
-        for line in cargo_content.lines() {
-            if line.contains("google-adk") || line.contains("adk-core") {
-                if let Some(version_start) = line.find("version = \"") {
-                    let version_start = version_start + 11; // Length of "version = \""
-                    if let Some(version_end) = line[version_start..].find('"') {
-                        return Some(line[version_start..version_start + version_end].to_string());
+    /// Find a manifest at the root, or up to `manifest_search_depth` levels below it.
+    /// Returns the path relative to the root; the shallowest match wins, ties broken by name.
+    fn locate_manifest(&self, root: &Path, manifest: &str) -> Option<PathBuf> {
+        let mut level = vec![PathBuf::new()];
+
+        for depth in 0..=self.manifest_search_depth {
+            if let Some(found) = level
+                .iter()
+                .map(|dir| dir.join(manifest))
+                .find(|candidate| vfs::is_file(root.join(candidate)))
+            {
+                return Some(found);
+            }
+
+            if depth == self.manifest_search_depth {
+                break;
+            }
+
+            let mut next_level = Vec::new();
+            for dir in &level {
+                if let Ok(entries) = vfs::read_dir(root.join(dir)) {
+                    for entry in entries.flatten() {
+                        if !vfs::is_dir(entry.path()) {
+                            continue;
+                        }
+                        let name = entry.file_name();
+                        if name.to_str().is_some_and(|name| self.is_skipped_dir(name)) {
+                            continue;
+                        }
+                        next_level.push(dir.join(name));
                     }
                 }
             }
+            next_level.sort();
+            level = next_level;
         }
+
         None
     }
 
-    /// Check for ADK-specific configuration files
-    fn check_adk_config_files<P: AsRef<Path>>(&self, path: P) -> Result<bool> {
-        let path = path.as_ref();
+    /// Check whether Cargo.toml declares a known ADK crate; see
+    /// [`Self::detect_rust_adk_dependencies`]
+    pub fn check_rust_adk_dependencies(&self, cargo_content: &str) -> bool {
+        !self.detect_rust_adk_dependencies(cargo_content).is_empty()
+    }
 
-        // Common ADK configuration file patterns
-        let adk_config_files = [
-            ".env",
-            ".env.template",
-            "adk.toml",
-            "adk-config.json",
-            "vertex-config.json",
-            "google-cloud-config.json",
-        ];
+    /// Names of the known ADK crates Cargo.toml depends on, found by parsing the manifest and
+    /// walking `[dependencies]`, `[build-dependencies]` and `[dev-dependencies]` (unless dev
+    /// dependencies are disabled), including their `[target.*]` variants. Renamed
+    /// dependencies report the real crate (`adk = { package = "google-adk" }` gives
+    /// `google-adk`). Manifests that are not valid TOML declare nothing.
+    pub fn detect_rust_adk_dependencies(&self, cargo_content: &str) -> Vec<String> {
+        let Ok(cargo) = cargo_content.parse::<toml::Table>() else {
+            return Vec::new();
+        };
 
-        for config_file in &adk_config_files {
-            let config_path = path.join(config_file);
-            if config_path.exists() {
-                // Check if the config file contains ADK-related content
-                if let Ok(content) = fs::read_to_string(&config_path) {
-                    if content.contains("GOOGLE_API_KEY")
-                        || content.contains("VERTEXAI")
-                        || content.contains("ADK")
-                        || content.contains("google-genai")
-                    {
-                        return Ok(true);
-                    }
-                }
-            }
+        let declared = Self::cargo_dependencies(&cargo, self.consider_dev_deps)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        self.matched_dependencies(declared, &self.adk_rust_dependencies)
+    }
+
+    /// Package name (after any `package = "..."` rename) and spec of every dependency in a
+    /// parsed Cargo.toml, including target-specific tables; dev dependencies only when
+    /// `include_dev` is set
+    fn cargo_dependencies(cargo: &toml::Table, include_dev: bool) -> Vec<(String, &toml::Value)> {
+        let mut tables = vec!["dependencies", "build-dependencies"];
+        if include_dev {
+            tables.push("dev-dependencies");
         }
+        let targets = cargo.get("target").and_then(|t| t.as_table());
+        let scopes = std::iter::once(cargo).chain(targets.into_iter().flat_map(|targets| {
+            targets.values().filter_map(|target| target.as_table())
+        }));
 
-        // Check for ADK-specific directory structures
-        let adk_directories = ["multi_tool_agent", "adk_agents", "src/expert", "src/review"];
+        scopes
+            .flat_map(|scope| tables.iter().filter_map(|table| scope.get(*table)?.as_table()))
+            .flat_map(|table| table.iter())
+            .map(|(name, spec)| {
+                let package = spec.get("package").and_then(|package| package.as_str());
+                (package.unwrap_or(name).to_string(), spec)
+            })
+            .collect()
+    }
 
-        for dir in &adk_directories {
-            if path.join(dir).is_dir() {
-                return Ok(true);
+    /// Declared names (deduplicated, in declaration order) matching any of `known`
+    fn matched_dependencies(&self, declared: Vec<String>, known: &[String]) -> Vec<String> {
+        let mut matched: Vec<String> = Vec::new();
+        for name in declared {
+            if known.iter().any(|dep| matching::normalized_dep_match(&name, dep)) && !matched.contains(&name) {
+                matched.push(name);
             }
         }
+        matched
+    }
 
-        Ok(false)
+    /// Attribute each matched name to `file` as a `signal`
+    fn attribute(signal: &str, file: &Path, matched: Vec<String>) -> Vec<SignalAttribution> {
+        matched
+            .into_iter()
+            .map(|matched| SignalAttribution {
+                signal: signal.to_string(),
+                file: file.to_path_buf(),
+                matched,
+            })
+            .collect()
     }
 
-    /// Estimate the total size of the project
-    fn estimate_project_size<P: AsRef<Path>>(&self, path: P) -> Result<u64> {
-        let path = path.as_ref();
-        let mut total_size = 0u64;
+    /// Requirement names matching a known ADK dependency
+    fn matched_python_adk_dependencies(&self, requirements_content: &str) -> Vec<String> {
+        self.matched_dependencies(Self::requirement_names(requirements_content), &self.adk_python_dependencies)
+    }
 
-        fn visit_dir(dir: &Path, total_size: &mut u64, max_size: u64) -> Result<()> {
-            if *total_size > max_size {
-                return Ok(()); // Stop if we exceed the limit
-            }
+    /// Match declared dependencies against known HTTP, database and service clients
+    fn find_external_integrations(&self, path: &Path, manifest_paths: &[PathBuf]) -> Vec<String> {
+        let declared = self.declared_dependency_names(path, manifest_paths);
+        let mut integrations: Vec<String> = INTEGRATION_DEPENDENCIES
+            .iter()
+            .filter(|known| declared.iter().any(|name| matching::normalized_dep_match(name, known)))
+            .map(|known| known.to_string())
+            .collect();
+        integrations.sort();
+        integrations
+    }
 
-            for entry in fs::read_dir(dir)? {
-                let entry = entry?;
-                let path = entry.path();
+    /// Find the web framework serving the agent over HTTP, from declared dependencies. When
+    /// several are declared the first in [`WEB_FRAMEWORKS`] wins.
+    fn detect_web_framework(&self, path: &Path, manifest_paths: &[PathBuf]) -> Option<String> {
+        let declared = self.declared_dependency_names(path, manifest_paths);
+        WEB_FRAMEWORKS
+            .iter()
+            .find(|framework| declared.iter().any(|name| matching::normalized_dep_match(name, framework)))
+            .map(|framework| framework.to_string())
+    }
 
-                // Skip common build/cache directories
-                if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-                    if matches!(
-                        name,
-                        "target" | "node_modules" | ".git" | "__pycache__" | ".venv"
-                    ) {
-                        continue;
-                    }
-                }
+    /// Score each of [`CATEGORY_SIGNALS`]: 3 per matching dependency, 2 per matching directory
+    /// (at the root or in `src/`) and 1 per README keyword. The best category needs a score of
+    /// at least 3, so a single README word is not enough.
+    fn infer_category(&self, path: &Path, manifest_paths: &[PathBuf]) -> Option<String> {
+        let declared = self.declared_dependency_names(path, manifest_paths);
+        let readme = ["README.md", "README.rst", "README.txt", "README"]
+            .iter()
+            .find_map(|name| vfs::read_to_string(path.join(name)).ok())
+            .unwrap_or_default()
+            .to_lowercase();
 
-                if path.is_dir() {
-                    visit_dir(&path, total_size, max_size)?;
-                } else if path.is_file() {
-                    if let Ok(metadata) = entry.metadata() {
-                        *total_size += metadata.len();
-                    }
-                }
+        let mut best: Option<(&str, usize)> = None;
+        for signals in CATEGORY_SIGNALS {
+            let dependencies = signals
+                .dependencies
+                .iter()
+                .filter(|dep| declared.iter().any(|name| matching::normalized_dep_match(name, dep)))
+                .count();
+            let directories = signals
+                .directories
+                .iter()
+                .filter(|dir| {
+                    vfs::is_dir(path.join(dir)) || vfs::is_dir(path.join("src").join(dir))
+                })
+                .count();
+            let keywords = signals
+                .readme_keywords
+                .iter()
+                .filter(|keyword| matching::content_contains_token(&readme, keyword))
+                .count();
+
+            let score = dependencies * 3 + directories * 2 + keywords;
+            if score >= 3 && best.is_none_or(|(_, best_score)| score > best_score) {
+                best = Some((signals.category, score));
             }
-            Ok(())
         }
 
-        visit_dir(path, &mut total_size, self.max_file_size)?;
-        Ok(total_size)
+        best.map(|(category, _)| category.to_string())
     }
 
-    /// Determine the project type based on collected information
-    fn determine_project_type(&self, info: &AdkProjectInfo) -> AdkProjectType {
-        let has_rust = info.has_cargo_toml;
-        let has_python = info.has_requirements_txt;
-        let has_adk = info.has_adk_dependencies || info.has_adk_config;
-
-        if !has_adk {
-            return AdkProjectType::None;
-        }
-
-        match (has_rust, has_python) {
-            (true, true) => AdkProjectType::Mixed,
-            (true, false) => {
-                // Check if it's an MCP server by looking for rmcp dependency
-                if info.root_path.join("Cargo.toml").exists() {
-                    if let Ok(cargo_content) = fs::read_to_string(info.root_path.join("Cargo.toml"))
-                    {
-                        if cargo_content.contains("rmcp") || cargo_content.contains("mcp") {
-                            return AdkProjectType::McpAdkServer;
-                        }
-                    }
-                }
-                AdkProjectType::RustAdk
-            }
-            (false, true) => AdkProjectType::PythonAdk,
-            (false, false) => {
-                // Has ADK config but no clear language indicators
-                if info.has_adk_config {
-                    AdkProjectType::PythonAdk // Default to Python for config-only detection
-                } else {
-                    AdkProjectType::None
+    /// Names of the dependencies declared by the Cargo.toml, requirements and pyproject.toml
+    /// manifests
+    fn declared_dependency_names(&self, path: &Path, manifest_paths: &[PathBuf]) -> Vec<String> {
+        let mut declared = Vec::new();
+        for manifest in manifest_paths {
+            let Ok(content) = vfs::read_to_string(path.join(manifest)) else {
+                continue;
+            };
+            if manifest.ends_with("Cargo.toml") {
+                declared.extend(Self::cargo_dependency_names(&content));
+            } else if manifest.ends_with("requirements.txt") {
+                declared.extend(Self::requirement_names(&content));
+            } else if manifest.ends_with("pyproject.toml") {
+                if let Ok(pyproject) = content.parse::<toml::Table>() {
+                    declared.extend(self.pyproject_dependency_names(&pyproject));
                 }
             }
         }
+        declared
     }
 
-    /// Check if a specific file should be processed based on size and type
-    pub fn should_process_file<P: AsRef<Path>>(&self, file_path: P) -> Result<bool> {
+    /// Check whether the project is managed by uv: a `uv.lock` next to the pyproject.toml,
+    /// or a `[tool.uv]` table in it
+    fn is_uv_project(&self, path: &Path) -> bool {
+        if !vfs::is_file(path.join("pyproject.toml")) {
+            return false;
+        }
+        vfs::is_file(path.join("uv.lock"))
+            || Self::read_toml_table(&path.join("pyproject.toml"))
+                .is_some_and(|pyproject| pyproject.get("tool").and_then(|t| t.get("uv")).is_some())
+    }
+
+    /// Check pyproject.toml `[project] dependencies`, `[dependency-groups]`,
+    /// `[tool.poetry.dependencies]` (plus Poetry dev and group tables) and `[tool.uv.sources]`
+    /// (e.g. ADK pinned to a git checkout) for ADK package names
+    fn matched_pyproject_adk_dependencies(&self, path: &Path) -> Vec<String> {
+        let Some(pyproject) = Self::read_toml_table(&path.join("pyproject.toml")) else {
+            return Vec::new();
+        };
+        self.matched_dependencies(self.pyproject_dependency_names(&pyproject), &self.adk_python_dependencies)
+    }
+
+    /// Names declared by pyproject.toml: PEP 621 dependencies, Poetry dependencies (other than
+    /// `python`) and `[tool.uv.sources]`, plus dependency groups and Poetry dev dependencies
+    /// when dev dependencies are considered
+    fn pyproject_dependency_names(&self, pyproject: &toml::Table) -> Vec<String> {
+        let requirement_strings = |value: Option<&toml::Value>| -> Vec<String> {
+            value
+                .and_then(|v| v.as_array())
+                .map(|items| {
+                    items.iter().filter_map(|item| item.as_str().map(str::to_string)).collect()
+                })
+                .unwrap_or_default()
+        };
+
+        let mut requirements =
+            requirement_strings(pyproject.get("project").and_then(|p| p.get("dependencies")));
+        let groups = pyproject
+            .get("dependency-groups")
+            .and_then(|g| g.as_table())
+            .filter(|_| self.consider_dev_deps);
+        if let Some(groups) = groups {
+            for group in groups.values() {
+                requirements.extend(requirement_strings(Some(group)));
+            }
+        }
+
+        let mut declared = Self::requirement_names(&requirements.join("\n"));
+        if let Some(poetry) = pyproject.get("tool").and_then(|t| t.get("poetry")) {
+            let mut tables: Vec<&toml::Value> = poetry.get("dependencies").into_iter().collect();
+            if self.consider_dev_deps {
+                tables.extend(poetry.get("dev-dependencies"));
+                if let Some(groups) = poetry.get("group").and_then(|g| g.as_table()) {
+                    tables.extend(groups.values().filter_map(|group| group.get("dependencies")));
+                }
+            }
+            declared.extend(
+                tables
+                    .into_iter()
+                    .filter_map(|table| table.as_table())
+                    .flat_map(|table| table.keys().cloned())
+                    .filter(|name| name != "python"),
+            );
+        }
+        if let Some(sources) = pyproject
+            .get("tool")
+            .and_then(|t| t.get("uv"))
+            .and_then(|uv| uv.get("sources"))
+            .and_then(|s| s.as_table())
+        {
+            declared.extend(sources.keys().cloned());
+        }
+
+        declared
+    }
+
+    /// Read the resolved version of the first known ADK package locked in `uv.lock`
+    fn extract_adk_version_from_uv_lock(&self, path: &Path) -> Option<String> {
+        let lock = Self::read_toml_table(&path.join("uv.lock"))?;
+        let packages = lock.get("package")?.as_array()?;
+
+        self.adk_python_dependencies.iter().find_map(|dep| {
+            packages.iter().find_map(|package| {
+                let name = package.get("name")?.as_str()?;
+                if !matching::normalized_dep_match(name, dep) {
+                    return None;
+                }
+                package.get("version")?.as_str().map(str::to_string)
+            })
+        })
+    }
+
+    /// Read and parse a TOML file, returning `None` if it is missing or malformed
+    fn read_toml_table(path: &Path) -> Option<toml::Table> {
+        vfs::read_to_string(path).ok()?.parse::<toml::Table>().ok()
+    }
+
+    /// Package names declared in any Cargo.toml dependency table, including dev and
+    /// target-specific dependencies; a manifest that is not valid TOML declares nothing
+    fn cargo_dependency_names(cargo_content: &str) -> Vec<String> {
+        let Ok(cargo) = cargo_content.parse::<toml::Table>() else {
+            return Vec::new();
+        };
+        Self::cargo_dependencies(&cargo, true).into_iter().map(|(name, _)| name).collect()
+    }
+
+    /// Collect requirement names from requirements.txt, ignoring versions, extras and markers
+    fn requirement_names(requirements_content: &str) -> Vec<String> {
+        requirements_content
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or_default().trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('-'))
+            .map(|line| {
+                line.chars()
+                    .take_while(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.'))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Extract ADK version from Cargo.toml if available
+    fn extract_adk_version_from_cargo(&self, cargo_content: &str) -> Option<String> {
+        matching::extract_dependency_version(cargo_content, &["google-adk", "adk-core"])
+    }
+
+    /// List `[lib]`, `[[bin]]` and `[[example]]` targets from Cargo.toml plus those Cargo
+    /// discovers automatically: `src/lib.rs`, `src/main.rs`, `src/bin/*.rs`,
+    /// `src/bin/*/main.rs` and `examples/*.rs`
+    fn detect_cargo_targets(&self, root: &Path, cargo_path: &Path) -> Vec<CargoTarget> {
+        let Some(cargo) = Self::read_toml_table(&root.join(cargo_path)) else {
+            return Vec::new();
+        };
+        let crate_dir = cargo_path.parent().unwrap_or(Path::new(""));
+        let package_name = cargo
+            .get("package")
+            .and_then(|p| p.get("name"))
+            .and_then(|n| n.as_str())
+            .unwrap_or_default()
+            .to_string();
+
+        let mut targets: Vec<CargoTarget> = Vec::new();
+        let mut add = |name: String, kind: CargoTargetKind, path: PathBuf| {
+            if !targets.iter().any(|t| t.kind == kind && t.name == name) {
+                targets.push(CargoTarget { name, kind, path: crate_dir.join(path) });
+            }
+        };
+
+        // Explicit declarations take precedence over discovered targets of the same name
+        if let Some(lib) = cargo.get("lib").and_then(|l| l.as_table()) {
+            let name = lib
+                .get("name")
+                .and_then(|n| n.as_str())
+                .map(str::to_string)
+                .unwrap_or_else(|| package_name.replace('-', "_"));
+            let path = lib.get("path").and_then(|p| p.as_str()).unwrap_or("src/lib.rs");
+            add(name, CargoTargetKind::Lib, PathBuf::from(path));
+        }
+        for (table, kind, default_dir) in [
+            ("bin", CargoTargetKind::Bin, "src/bin"),
+            ("example", CargoTargetKind::Example, "examples"),
+        ] {
+            let declared = cargo.get(table).and_then(|t| t.as_array()).cloned().unwrap_or_default();
+            for target in declared {
+                let Some(name) = target.get("name").and_then(|n| n.as_str()) else {
+                    continue;
+                };
+                let path = target
+                    .get("path")
+                    .and_then(|p| p.as_str())
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| Path::new(default_dir).join(format!("{}.rs", name)));
+                add(name.to_string(), kind, path);
+            }
+        }
+
+        let crate_root = root.join(crate_dir);
+        if vfs::is_file(crate_root.join("src/lib.rs")) {
+            add(package_name.replace('-', "_"), CargoTargetKind::Lib, PathBuf::from("src/lib.rs"));
+        }
+        if vfs::is_file(crate_root.join("src/main.rs")) {
+            add(package_name.clone(), CargoTargetKind::Bin, PathBuf::from("src/main.rs"));
+        }
+        for (dir, kind) in [("src/bin", CargoTargetKind::Bin), ("examples", CargoTargetKind::Example)] {
+            let Ok(entries) = vfs::read_dir(crate_root.join(dir)) else {
+                continue;
+            };
+            let mut discovered: Vec<(String, PathBuf)> = entries
+                .flatten()
+                .filter_map(|entry| {
+                    let entry_path = entry.path();
+                    let file_name = entry.file_name().to_string_lossy().into_owned();
+                    if vfs::is_dir(&entry_path) && vfs::is_file(entry_path.join("main.rs")) {
+                        Some((file_name.clone(), Path::new(dir).join(file_name).join("main.rs")))
+                    } else {
+                        let stem = file_name.strip_suffix(".rs")?;
+                        Some((stem.to_string(), Path::new(dir).join(&file_name)))
+                    }
+                })
+                .collect();
+            discovered.sort();
+            for (name, path) in discovered {
+                add(name, kind, path);
+            }
+        }
+
+        targets
+    }
+
+    /// Get recommendations for a detected project
+    pub fn get_project_recommendations(&self, info: &AdkProjectInfo) -> Vec<String> {
+        let mut recommendations = Vec::new();
+
+        if let Some(rmcp_version) = &info.rmcp_version {
+            if version_below(rmcp_version, &self.min_rmcp_version) {
+                recommendations.push(format!(
+                    "Upgrade rmcp from {} to at least {}",
+                    rmcp_version, self.min_rmcp_version
+                ));
+            }
+        }
+
+        for loose in &info.loose_versions {
+            let requirement = if loose.requirement.is_empty() {
+                "unversioned".to_string()
+            } else {
+                format!("currently \"{}\"", loose.requirement)
+            };
+            recommendations.push(format!(
+                "Pin {} ({}) in {} to a specific version",
+                loose.dependency,
+                requirement,
+                loose.manifest.display()
+            ));
+        }
+
+        recommendations
+    }
+
+    /// Collect version requirements from Cargo.toml dependency tables and requirements.txt,
+    /// keeping those that are wildcard or unbounded and in scope of the pin check
+    fn find_loose_versions(&self, path: &Path, manifest_paths: &[PathBuf]) -> Vec<LooseVersion> {
+        let mut loose = Vec::new();
+
+        for manifest in manifest_paths {
+            let Ok(content) = vfs::read_to_string(path.join(manifest)) else {
+                continue;
+            };
+
+            let Some(requirements) = Self::declared_requirements(manifest, &content) else {
+                continue;
+            };
+
+            for (dependency, requirement) in requirements {
+                let in_scope = self.pin_check == PinCheck::All
+                    || self
+                        .adk_rust_dependencies
+                        .iter()
+                        .chain(&self.adk_python_dependencies)
+                        .any(|dep| matching::normalized_dep_match(&dependency, dep));
+                if in_scope && is_loose_requirement(&requirement) {
+                    loose.push(LooseVersion {
+                        dependency,
+                        requirement,
+                        manifest: manifest.clone(),
+                    });
+                }
+            }
+        }
+
+        loose
+    }
+
+    /// Dependency names and version requirements declared by a Cargo.toml or requirements
+    /// file. Path, git and workspace dependencies are skipped; `None` if Cargo.toml is invalid
+    /// or the manifest is of another kind.
+    fn declared_requirements(manifest: &Path, content: &str) -> Option<Vec<(String, String)>> {
+        if manifest.ends_with("Cargo.toml") {
+            let cargo = content.parse::<toml::Table>().ok()?;
+            Some(
+                ["dependencies", "dev-dependencies", "build-dependencies"]
+                    .iter()
+                    .filter_map(|table| cargo.get(*table).and_then(|t| t.as_table()))
+                    .flat_map(|table| table.iter())
+                    .filter_map(|(name, spec)| {
+                        let version = match spec {
+                            toml::Value::String(version) => version.as_str(),
+                            toml::Value::Table(table) => table.get("version")?.as_str()?,
+                            _ => return None,
+                        };
+                        Some((name.clone(), version.to_string()))
+                    })
+                    .collect(),
+            )
+        } else if manifest.ends_with("requirements.txt") {
+            Some(
+                content
+                    .lines()
+                    .map(|line| line.split('#').next().unwrap_or_default().trim())
+                    .filter(|line| !line.is_empty() && !line.starts_with('-'))
+                    .filter_map(|line| {
+                        let line = line.split(';').next().unwrap_or_default();
+                        let name: String = Self::requirement_names(line).into_iter().next()?;
+                        let spec = line[name.len()..].trim();
+                        // Skip extras such as `google-adk[eval]>=1.0`
+                        let spec = match spec.strip_prefix('[') {
+                            Some(rest) => rest.split_once(']').map_or("", |(_, spec)| spec).trim(),
+                            None => spec,
+                        };
+                        Some((name, spec.to_string()))
+                    })
+                    .collect(),
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Dependencies declared in Cargo.toml or a Poetry pyproject.toml that have no `[[package]]`
+    /// entry in the `Cargo.lock`/`poetry.lock` next to it. Renamed crates are looked up by
+    /// their real name; manifests without a lockfile are skipped.
+    fn find_lockfile_missing(path: &Path) -> Vec<String> {
+        const CARGO_TABLES: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
+
+        let mut missing = Vec::new();
+        for (manifest, lockfile) in [("Cargo.toml", "Cargo.lock"), ("pyproject.toml", "poetry.lock")] {
+            let (Some(manifest_table), Some(lock)) = (
+                Self::read_toml_table(&path.join(manifest)),
+                Self::read_toml_table(&path.join(lockfile)),
+            ) else {
+                continue;
+            };
+
+            let mut tables: Vec<&toml::Table> = Vec::new();
+            if manifest == "Cargo.toml" {
+                // The package's own tables, then each `[target.*]` section's
+                let mut scopes = vec![&manifest_table];
+                if let Some(targets) = manifest_table.get("target").and_then(|t| t.as_table()) {
+                    scopes.extend(targets.values().filter_map(|spec| spec.as_table()));
+                }
+                for scope in scopes {
+                    tables.extend(CARGO_TABLES.iter().filter_map(|key| scope.get(*key)?.as_table()));
+                }
+            } else if let Some(poetry) = manifest_table.get("tool").and_then(|t| t.get("poetry")) {
+                tables.extend(
+                    ["dependencies", "dev-dependencies"]
+                        .iter()
+                        .filter_map(|key| poetry.get(*key)?.as_table()),
+                );
+                if let Some(groups) = poetry.get("group").and_then(|g| g.as_table()) {
+                    tables.extend(groups.values().filter_map(|group| group.get("dependencies")?.as_table()));
+                }
+            }
+
+            let locked: Vec<&str> = lock
+                .get("package")
+                .and_then(|p| p.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|package| package.get("name")?.as_str())
+                .collect();
+
+            for (name, spec) in tables.into_iter().flatten() {
+                let name = spec.get("package").and_then(|p| p.as_str()).unwrap_or(name);
+                // Poetry lists the Python version constraint alongside the dependencies
+                if name == "python" && lockfile == "poetry.lock" {
+                    continue;
+                }
+                if !locked.iter().any(|locked| matching::normalized_dep_match(locked, name))
+                    && !missing.iter().any(|found: &String| found == name)
+                {
+                    missing.push(name.to_string());
+                }
+            }
+        }
+
+        missing
+    }
+
+    /// Find dependencies declared more than once with different version requirements: across
+    /// Cargo.toml dependency tables (including `[target.*]` ones), or repeatedly in a
+    /// requirements file
+    fn find_duplicate_dependencies(&self, path: &Path, manifest_paths: &[PathBuf]) -> Vec<String> {
+        const CARGO_TABLES: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
+
+        let mut duplicates = Vec::new();
+        for manifest in manifest_paths {
+            let Ok(content) = vfs::read_to_string(path.join(manifest)) else {
+                continue;
+            };
+
+            // (table, name, version requirement) for every declaration in the manifest
+            let mut declarations: Vec<(String, String, String)> = Vec::new();
+            if manifest.ends_with("Cargo.toml") {
+                let Ok(cargo) = content.parse::<toml::Table>() else {
+                    continue;
+                };
+                let mut tables: Vec<(String, &toml::Table)> = CARGO_TABLES
+                    .iter()
+                    .filter_map(|key| Some((key.to_string(), cargo.get(*key)?.as_table()?)))
+                    .collect();
+                if let Some(targets) = cargo.get("target").and_then(|t| t.as_table()) {
+                    for (target, spec) in targets {
+                        tables.extend(CARGO_TABLES.iter().filter_map(|key| {
+                            Some((format!("target.{}.{}", target, key), spec.get(*key)?.as_table()?))
+                        }));
+                    }
+                }
+                for (table, entries) in tables {
+                    for (name, spec) in entries {
+                        let version = match spec {
+                            toml::Value::String(version) => version.clone(),
+                            toml::Value::Table(spec) => spec
+                                .get("version")
+                                .and_then(|v| v.as_str())
+                                .unwrap_or("*")
+                                .to_string(),
+                            _ => "*".to_string(),
+                        };
+                        declarations.push((table.clone(), name.clone(), version));
+                    }
+                }
+            } else {
+                let requirements = Self::declared_requirements(manifest, &content).unwrap_or_default();
+                declarations.extend(requirements.into_iter().map(|(name, requirement)| {
+                    (manifest.display().to_string(), name, requirement)
+                }));
+            }
+
+            for (i, (table, name, version)) in declarations.iter().enumerate() {
+                let earlier = declarations[..i].iter().find(|(_, other, other_version)| {
+                    matching::normalized_dep_match(other, name) && other_version != version
+                });
+                if let Some((other_table, _, other_version)) = earlier {
+                    duplicates.push(format!(
+                        "{} is declared in [{}] as {:?} and in [{}] as {:?} ({})",
+                        name,
+                        other_table,
+                        other_version,
+                        table,
+                        version,
+                        manifest.display()
+                    ));
+                }
+            }
+        }
+
+        duplicates
+    }
+
+    /// Collect declared target platforms: Cargo `[target.*]` keys (`cfg(unix)`, target triples)
+    /// and `targets` lists under `[package.metadata]`, plus `Python X.Y` and operating system
+    /// names from pyproject classifiers
+    fn find_supported_platforms(&self, path: &Path, manifest_paths: &[PathBuf]) -> Vec<String> {
+        let mut platforms: Vec<String> = Vec::new();
+        let mut add = |platform: String| {
+            if !platforms.contains(&platform) {
+                platforms.push(platform);
+            }
+        };
+
+        let cargo = manifest_paths
+            .iter()
+            .find(|manifest| manifest.ends_with("Cargo.toml"))
+            .and_then(|manifest| Self::read_toml_table(&path.join(manifest)));
+        if let Some(cargo) = cargo {
+            if let Some(targets) = cargo.get("target").and_then(|t| t.as_table()) {
+                targets.keys().cloned().for_each(&mut add);
+            }
+            let metadata = cargo
+                .get("package")
+                .and_then(|p| p.get("metadata"))
+                .and_then(|m| m.as_table());
+            for tool in metadata.into_iter().flat_map(|m| m.values()) {
+                let declared = tool.get("targets").and_then(|t| t.as_array());
+                for target in declared.into_iter().flatten().filter_map(|t| t.as_str()) {
+                    add(target.to_string());
+                }
+            }
+        }
+
+        let classifiers = Self::read_toml_table(&path.join("pyproject.toml"))
+            .and_then(|pyproject| pyproject.get("project")?.get("classifiers")?.as_array().cloned())
+            .unwrap_or_default();
+        for classifier in classifiers.iter().filter_map(|c| c.as_str()) {
+            let parts: Vec<&str> = classifier.split("::").map(str::trim).collect();
+            match parts.as_slice() {
+                ["Programming Language", "Python", version]
+                    if version.starts_with(|c: char| c.is_ascii_digit()) =>
+                {
+                    add(format!("Python {}", version));
+                }
+                ["Operating System", .., os] => add(os.to_string()),
+                _ => {}
+            }
+        }
+
+        platforms
+    }
+
+    /// Find directories holding vendored dependencies: `directory` sources that
+    /// `.cargo/config.toml` replaces crates.io (or another source) with, and wheel directories
+    /// (`vendor/`, `wheels/`, `wheelhouse/`) containing Python distributions. Paths are
+    /// relative to the project root.
+    fn find_vendor_dirs(&self, path: &Path) -> Vec<PathBuf> {
+        let mut vendor_dirs = Vec::new();
+
+        let cargo_config = ["config.toml", "config"]
+            .iter()
+            .find_map(|name| Self::read_toml_table(&path.join(".cargo").join(name)));
+        if let Some(sources) = cargo_config
+            .as_ref()
+            .and_then(|config| config.get("source"))
+            .and_then(|sources| sources.as_table())
+        {
+            for replacement in sources
+                .values()
+                .filter_map(|source| source.get("replace-with")?.as_str())
+            {
+                let directory = sources
+                    .get(replacement)
+                    .and_then(|source| source.get("directory"))
+                    .and_then(|dir| dir.as_str());
+                if let Some(directory) = directory {
+                    let directory = PathBuf::from(directory.trim_start_matches("./"));
+                    if !vendor_dirs.contains(&directory) {
+                        vendor_dirs.push(directory);
+                    }
+                }
+            }
+        }
+
+        for dir in ["vendor", "wheels", "wheelhouse"] {
+            let Ok(entries) = vfs::read_dir(path.join(dir)) else {
+                continue;
+            };
+            let has_distributions = entries.flatten().any(|entry| {
+                let name = entry.file_name().to_string_lossy().to_lowercase();
+                name.ends_with(".whl") || name.ends_with(".tar.gz")
+            });
+            if has_distributions && !vendor_dirs.iter().any(|known| known == Path::new(dir)) {
+                vendor_dirs.push(PathBuf::from(dir));
+            }
+        }
+
+        vendor_dirs
+    }
+
+    /// Flag related Google packages declared on incompatible major versions: packages whose
+    /// major differs from what the declared ADK major expects (see [`GOOGLE_DEP_COMPAT`]), and
+    /// the same package declared with different majors across manifests
+    fn find_google_dep_mismatches(&self, path: &Path, manifest_paths: &[PathBuf]) -> Vec<String> {
+        let mut majors: Vec<(String, u64, PathBuf)> = Vec::new();
+        for manifest in manifest_paths {
+            let Ok(content) = vfs::read_to_string(path.join(manifest)) else {
+                continue;
+            };
+            for (dependency, requirement) in
+                Self::declared_requirements(manifest, &content).unwrap_or_default()
+            {
+                let tracked = GOOGLE_DEP_COMPAT
+                    .iter()
+                    .flat_map(|(adk, _, related)| {
+                        std::iter::once(*adk).chain(related.iter().map(|(dep, _)| *dep))
+                    })
+                    .find(|dep| matching::normalized_dep_match(&dependency, dep));
+                if let (Some(dep), Some(major)) = (tracked, requirement_major(&requirement)) {
+                    majors.push((dep.to_string(), major, manifest.clone()));
+                }
+            }
+        }
+
+        let mut mismatches = Vec::new();
+        for (i, (dep, major, manifest)) in majors.iter().enumerate() {
+            if let Some((_, other, other_manifest)) =
+                majors[..i].iter().find(|(d, m, _)| d == dep && m != major)
+            {
+                mismatches.push(format!(
+                    "{} is declared as {}.x in {} but {}.x in {}",
+                    dep,
+                    other,
+                    other_manifest.display(),
+                    major,
+                    manifest.display()
+                ));
+            }
+        }
+        for (adk, adk_major, related) in GOOGLE_DEP_COMPAT {
+            if !majors.iter().any(|(dep, major, _)| dep == adk && major == adk_major) {
+                continue;
+            }
+            for (dep, expected) in related.iter() {
+                for (_, major, manifest) in majors.iter().filter(|(d, _, _)| d == dep) {
+                    if major != expected {
+                        mismatches.push(format!(
+                            "{} {}.x in {} is incompatible with {} {}.x (expects {}.x)",
+                            dep,
+                            major,
+                            manifest.display(),
+                            adk,
+                            adk_major,
+                            expected
+                        ));
+                    }
+                }
+            }
+        }
+
+        mismatches.sort();
+        mismatches.dedup();
+        mismatches
+    }
+
+    /// Find top-level Python packages in both the flat (`pkg/`) and src (`src/pkg/`) layouts.
+    /// Directories with an `__init__.py` are regular packages; under `src/`, directories of
+    /// Python modules without one are treated as PEP 420 namespace packages.
+    fn find_python_packages(&self, path: &Path) -> Vec<PathBuf> {
+        fn is_candidate(dir: &Path) -> bool {
+            match dir.file_name().and_then(|n| n.to_str()) {
+                Some(name) => {
+                    !name.starts_with('.')
+                        && !matches!(
+                            name,
+                            "target"
+                                | "node_modules"
+                                | "__pycache__"
+                                | "src"
+                                | "tests"
+                                | "test"
+                                | "docs"
+                                | "examples"
+                                | "scripts"
+                                | "build"
+                                | "dist"
+                        )
+                        && !name.ends_with(".egg-info")
+                }
+                None => false,
+            }
+        }
+
+        fn contains_python(dir: &Path) -> bool {
+            vfs::read_dir(dir)
+                .map(|entries| {
+                    entries.flatten().any(|entry| {
+                        let entry_path = entry.path();
+                        (vfs::is_file(&entry_path)
+                            && entry_path.extension().and_then(|e| e.to_str()) == Some("py"))
+                            || (vfs::is_dir(&entry_path)
+                                && vfs::is_file(entry_path.join("__init__.py")))
+                    })
+                })
+                .unwrap_or(false)
+        }
+
+        let mut packages = Vec::new();
+
+        for (layout_dir, allow_namespace) in [("", false), ("src", true)] {
+            let entries = match vfs::read_dir(path.join(layout_dir)) {
+                Ok(entries) => entries,
+                Err(_) => continue,
+            };
+
+            for entry in entries.flatten() {
+                let dir = entry.path();
+                let skipped = dir
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|name| self.is_skipped_dir(name));
+                if !vfs::is_dir(&dir) || skipped || !is_candidate(&dir) {
+                    continue;
+                }
+
+                let is_package = vfs::is_file(dir.join("__init__.py"))
+                    || (allow_namespace && contains_python(&dir));
+                if is_package {
+                    packages.push(Path::new(layout_dir).join(entry.file_name()));
+                }
+            }
+        }
+
+        packages.sort();
+        packages
+    }
+
+    /// Extract console scripts from `pyproject.toml` (`[project.scripts]` or
+    /// `[tool.poetry.scripts]`) and `setup.cfg` (`console_scripts` in `[options.entry_points]`)
+    fn extract_python_entry_points(&self, path: &Path) -> HashMap<String, String> {
+        let mut entry_points = HashMap::new();
+
+        if let Some(pyproject) = vfs::read_to_string(path.join("pyproject.toml"))
+            .ok()
+            .and_then(|content| content.parse::<toml::Table>().ok())
+        {
+            let script_tables = [
+                pyproject.get("project").and_then(|p| p.get("scripts")),
+                pyproject
+                    .get("tool")
+                    .and_then(|t| t.get("poetry"))
+                    .and_then(|p| p.get("scripts")),
+            ];
+
+            for scripts in script_tables.iter().flatten().filter_map(|s| s.as_table()) {
+                for (command, target) in scripts {
+                    if let Some(target) = target.as_str() {
+                        entry_points.insert(command.clone(), target.to_string());
+                    }
+                }
+            }
+        }
+
+        if let Ok(setup_cfg) = vfs::read_to_string(path.join("setup.cfg")) {
+            let mut in_entry_points = false;
+            let mut in_console_scripts = false;
+
+            for line in setup_cfg.lines() {
+                let trimmed = line.trim();
+                if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with(';') {
+                    continue;
+                }
+
+                if trimmed.starts_with('[') {
+                    in_entry_points = trimmed == "[options.entry_points]";
+                    in_console_scripts = false;
+                    continue;
+                }
+                if !in_entry_points {
+                    continue;
+                }
+
+                // Group keys start at column 0; their entries are indented continuation lines
+                let entry = if line.starts_with(char::is_whitespace) {
+                    trimmed
+                } else {
+                    let (group, value) = trimmed.split_once('=').unwrap_or((trimmed, ""));
+                    in_console_scripts = group.trim() == "console_scripts";
+                    value.trim()
+                };
+                if !in_console_scripts {
+                    continue;
+                }
+
+                if let Some((command, target)) = entry.split_once('=') {
+                    entry_points.insert(command.trim().to_string(), target.trim().to_string());
+                }
+            }
+        }
+
+        entry_points
+    }
+
+    /// Read the `description` field from Cargo.toml (`[package]`) or pyproject.toml
+    /// (`[project]` or `[tool.poetry]`)
+    fn extract_manifest_description(&self, path: &Path, manifest_paths: &[PathBuf]) -> Option<String> {
+        let cargo_path = manifest_paths
+            .iter()
+            .find(|manifest| manifest.ends_with("Cargo.toml"))
+            .map(|manifest| path.join(manifest));
+        let candidates = cargo_path
+            .into_iter()
+            .map(|manifest| (manifest, &["package"][..]))
+            .chain(std::iter::once((path.join("pyproject.toml"), &["project", "tool.poetry"][..])));
+
+        for (manifest, sections) in candidates {
+            let table = match vfs::read_to_string(&manifest)
+                .ok()
+                .and_then(|content| content.parse::<toml::Table>().ok())
+            {
+                Some(table) => table,
+                None => continue,
+            };
+
+            for section in sections {
+                let description = section
+                    .split('.')
+                    .try_fold(&table, |table, key| table.get(key).and_then(|v| v.as_table()))
+                    .and_then(|section| section.get("description"))
+                    .and_then(|v| v.as_str())
+                    .map(|v| v.trim())
+                    .filter(|v| !v.is_empty());
+                if let Some(description) = description {
+                    return Some(description.to_string());
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Take the first prose paragraph of the README, skipping headings, badges and HTML
+    fn extract_readme_description(&self, path: &Path) -> Option<String> {
+        let readme_names = ["README.md", "README.rst", "README.txt", "README"];
+        let content = readme_names
+            .iter()
+            .find_map(|name| vfs::read_to_string(path.join(name)).ok())?;
+
+        let mut paragraph: Vec<&str> = Vec::new();
+        for line in content.lines() {
+            let line = line.trim();
+            let is_decoration = line.starts_with('#')
+                || line.starts_with("![")
+                || line.starts_with("[![")
+                || line.starts_with('<')
+                || (!line.is_empty() && line.chars().all(|c| matches!(c, '=' | '-' | '~' | '*')));
+
+            if line.is_empty() || is_decoration {
+                if !paragraph.is_empty() {
+                    break;
+                }
+                continue;
+            }
+            paragraph.push(line);
+        }
+
+        if paragraph.is_empty() {
+            None
+        } else {
+            Some(paragraph.join(" "))
+        }
+    }
+
+    /// Parse targets from the justfile, Makefile and Taskfile at the project root
+    fn detect_task_targets(&self, path: &Path) -> Vec<TaskTarget> {
+        let task_files = [
+            "justfile",
+            "Justfile",
+            ".justfile",
+            "Makefile",
+            "makefile",
+            "GNUmakefile",
+            "Taskfile.yml",
+            "Taskfile.yaml",
+        ];
+        let mut targets = Vec::new();
+
+        for task_file in &task_files {
+            let content = match vfs::read_to_string(path.join(task_file)) {
+                Ok(content) => content,
+                Err(_) => continue,
+            };
+
+            let parsed = if task_file.starts_with("Taskfile") {
+                Self::parse_taskfile_targets(&content)
+            } else {
+                Self::parse_recipe_targets(&content, task_file.ends_with("justfile"))
+            };
+
+            for (name, body) in parsed {
+                targets.push(TaskTarget {
+                    looks_adk: Self::looks_like_adk_task(&name, &body),
+                    name,
+                    source: PathBuf::from(task_file),
+                });
+            }
+        }
+
+        targets
+    }
+
+    /// Parse justfile recipes and Makefile targets, which share the
+    /// `name ...: deps` header followed by an indented body
+    fn parse_recipe_targets(content: &str, is_justfile: bool) -> Vec<(String, String)> {
+        let mut targets: Vec<(String, String)> = Vec::new();
+        let mut in_body = false;
+
+        for line in content.lines() {
+            if line.starts_with(char::is_whitespace) {
+                if in_body && !line.trim().is_empty() {
+                    if let Some((_, body)) = targets.last_mut() {
+                        body.push_str(line.trim());
+                        body.push('\n');
+                    }
+                }
+                continue;
+            }
+
+            in_body = false;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            // Skip assignments (`x := y`, `x ?= y`, `x = y`) and lines without a header colon
+            let colon = match line.find(':') {
+                Some(colon) => colon,
+                None => continue,
+            };
+            let header = &line[..colon];
+            let tokens: Vec<&str> = header.split_whitespace().collect();
+            let is_assignment = line[colon..].starts_with(":=")
+                || tokens.first().is_some_and(|token| token.contains('='))
+                || tokens.get(1).is_some_and(|token| matches!(*token, "=" | "?=" | "+=" | "!="));
+            if is_assignment {
+                continue;
+            }
+            // Skip just settings, directives and `[attribute]` lines that use a colon
+            if header.starts_with('[')
+                || ["set ", "alias ", "export ", "import ", "mod "].iter().any(|d| header.starts_with(d))
+            {
+                continue;
+            }
+
+            // A justfile header is `name params...`; a Makefile header lists one or more targets
+            let names: &[&str] = if is_justfile {
+                &tokens[..tokens.len().min(1)]
+            } else if header.contains('$') || header.contains('%') {
+                &[]
+            } else {
+                &tokens
+            };
+
+            for name in names {
+                let name = name.trim_start_matches('@');
+                // Special Makefile targets such as `.PHONY` are not tasks
+                if name.is_empty() || name.starts_with('.') {
+                    continue;
+                }
+                targets.push((name.to_string(), String::new()));
+                in_body = true;
+            }
+        }
+
+        targets
+    }
+
+    /// Parse task names from the top-level `tasks:` mapping of a Taskfile
+    fn parse_taskfile_targets(content: &str) -> Vec<(String, String)> {
+        let mut targets: Vec<(String, String)> = Vec::new();
+        let mut in_tasks = false;
+        let mut task_indent = None;
+
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let indent = line.len() - line.trim_start().len();
+            if indent == 0 {
+                in_tasks = trimmed == "tasks:";
+                continue;
+            }
+            if !in_tasks {
+                continue;
+            }
+
+            let task_indent = *task_indent.get_or_insert(indent);
+            if indent == task_indent {
+                if let Some(name) = trimmed.strip_suffix(':').or_else(|| trimmed.split_once(": ").map(|(name, _)| name)) {
+                    targets.push((name.trim_matches(|c| c == '"' || c == '\'').to_string(), String::new()));
+                }
+            } else if indent > task_indent {
+                if let Some((_, body)) = targets.last_mut() {
+                    body.push_str(trimmed);
+                    body.push('\n');
+                }
+            }
+        }
+
+        targets
+    }
+
+    /// Check whether a task name or body suggests an ADK workflow
+    fn looks_like_adk_task(name: &str, body: &str) -> bool {
+        let name = name.to_lowercase();
+        let adk_keywords = ["adk", "agent", "deploy", "eval", "serve", "a2a", "mcp"];
+
+        adk_keywords.iter().any(|keyword| name.contains(keyword))
+            || body.lines().any(|line| {
+                let line = line.trim_start_matches(['@', '-']).trim_start();
+                line.starts_with("adk ") || line.contains(" adk ")
+            })
+    }
+
+    /// List what prevents publishing: missing `[package]` metadata for crates, or a missing
+    /// `[build-system]`/`[project]` setup for Python wheels and sdists
+    fn find_publish_blockers(&self, path: &Path, manifest_paths: &[PathBuf]) -> Vec<String> {
+        let mut blockers = Vec::new();
+        let cargo_path = manifest_paths.iter().find(|manifest| manifest.ends_with("Cargo.toml"));
+        let pyproject_path = path.join("pyproject.toml");
+        let has_python = vfs::is_file(&pyproject_path)
+            || vfs::is_file(path.join("setup.py"))
+            || manifest_paths.iter().any(|manifest| manifest.ends_with("requirements.txt"));
+
+        if cargo_path.is_none() && !has_python {
+            blockers.push("No Cargo.toml or Python packaging manifest found".to_string());
+            return blockers;
+        }
+
+        if let Some(cargo_path) = cargo_path {
+            let manifest = vfs::read_to_string(path.join(cargo_path))
+                .ok()
+                .and_then(|content| content.parse::<toml::Table>().ok());
+            match manifest.as_ref().and_then(|m| m.get("package")).and_then(|p| p.as_table()) {
+                None if manifest.is_none() => blockers.push("Cargo.toml does not parse".to_string()),
+                None => blockers.push("Cargo.toml has no [package] section".to_string()),
+                Some(package) => {
+                    for field in ["description", "license", "repository"] {
+                        // `license-file` is an accepted alternative to `license`
+                        let present = package.contains_key(field)
+                            || (field == "license" && package.contains_key("license-file"));
+                        if !present {
+                            blockers.push(format!("Cargo.toml [package] is missing `{}`", field));
+                        }
+                    }
+                    if package.get("publish").and_then(|v| v.as_bool()) == Some(false) {
+                        blockers.push("Cargo.toml sets `publish = false`".to_string());
+                    }
+                }
+            }
+        }
+
+        if has_python && !vfs::is_file(path.join("setup.py")) {
+            let pyproject = vfs::read_to_string(&pyproject_path)
+                .ok()
+                .and_then(|content| content.parse::<toml::Table>().ok());
+            match pyproject {
+                None if !vfs::is_file(&pyproject_path) => {
+                    blockers.push("No pyproject.toml or setup.py to build a wheel/sdist".to_string())
+                }
+                None => blockers.push("pyproject.toml does not parse".to_string()),
+                Some(pyproject) => {
+                    if !pyproject.contains_key("build-system") {
+                        blockers.push("pyproject.toml has no [build-system] table".to_string());
+                    }
+                    let has_project_metadata = pyproject.contains_key("project")
+                        || pyproject.get("tool").and_then(|t| t.get("poetry")).is_some();
+                    if !has_project_metadata {
+                        blockers.push("pyproject.toml has no [project] metadata".to_string());
+                    }
+                }
+            }
+        }
+
+        blockers
+    }
+
+    /// List every file under the project outside skipped directories and `vendor_dirs`,
+    /// relative to the root and sorted, with its size. Symlinks to files are followed;
+    /// symlinked directories are not entered, so link cycles cannot loop.
+    fn walk_project(
+        &self,
+        path: &Path,
+        vendor_dirs: &[PathBuf],
+    ) -> std::io::Result<Vec<(PathBuf, u64)>> {
+        fn visit_dir(
+            detector: &AdkProjectDetector,
+            dir: &Path,
+            files: &mut Vec<(PathBuf, u64)>,
+            vendor_dirs: &[PathBuf],
+        ) -> std::io::Result<()> {
+            for entry in vfs::read_dir(dir)? {
+                let entry = entry?;
+                let path = entry.path();
+
+                // Skip build/cache directories
+                if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+                    if detector.is_skipped_dir(name) {
+                        continue;
+                    }
+                }
+
+                let Ok(mut metadata) = entry.metadata() else {
+                    continue;
+                };
+                if metadata.is_dir() {
+                    if !vendor_dirs.contains(&path) {
+                        visit_dir(detector, &path, files, vendor_dirs)?;
+                    }
+                    continue;
+                }
+                if metadata.is_symlink() {
+                    let Ok(target) = vfs::metadata(&path) else {
+                        continue;
+                    };
+                    metadata = target;
+                }
+                if metadata.is_file() {
+                    files.push((path, metadata.len()));
+                }
+            }
+            Ok(())
+        }
+
+        let vendor_dirs: Vec<PathBuf> = vendor_dirs.iter().map(|dir| path.join(dir)).collect();
+        let mut files = Vec::new();
+        visit_dir(self, path, &mut files, &vendor_dirs)?;
+        let mut files: Vec<(PathBuf, u64)> = files
+            .into_iter()
+            .filter_map(|(file, size)| Some((file.strip_prefix(path).ok()?.to_path_buf(), size)))
+            .collect();
+        files.sort();
+        Ok(files)
+    }
+
+    /// Walk the project and read the sources the content analyzers look at, see [`ProjectFiles`]
+    fn read_project_files(
+        &self,
+        path: &Path,
+        info: &AdkProjectInfo,
+    ) -> std::io::Result<ProjectFiles> {
+        let files = self.walk_project(path, &info.vendor_dirs)?;
+
+        // `adk web` loads agents from `<dir>/agent.py`, with or without an `__init__.py`
+        let agent_dirs: Vec<&Path> = files
+            .iter()
+            .filter(|(file, _)| file.components().count() == 2 && file.ends_with("agent.py"))
+            .filter_map(|(file, _)| file.parent())
+            .collect();
+        let in_scanned_dir = |file: &Path| {
+            let mut components = file.components();
+            let top = components.next().and_then(|dir| dir.as_os_str().to_str());
+            components.next().is_none()
+                || top.is_some_and(|dir| SOURCE_SCAN_DIRS.contains(&dir))
+                || agent_dirs.iter().any(|dir| file.starts_with(dir))
+                || info.python_packages.iter().any(|package| file.starts_with(package))
+        };
+
+        let mut budget = self.source_scan_budget;
+        let mut contents = Vec::new();
+        for (file, size) in &files {
+            let is_source = ProjectFiles::has_extension(file, ProjectFiles::READ_EXTENSIONS)
+                || ProjectFiles::is_env_file(file);
+            if !is_source || *size > self.max_file_size || !in_scanned_dir(file) {
+                continue;
+            }
+            if *size > budget {
+                break;
+            }
+            if let Ok(content) = vfs::read_to_string(path.join(file)) {
+                budget = budget.saturating_sub(content.len() as u64);
+                contents.push((file.clone(), content));
+            }
+        }
+
+        Ok(ProjectFiles { files, contents })
+    }
+
+    /// Read a file as text unless it exceeds the file size limit or is not UTF-8
+    fn read_within_limit(&self, file: &Path) -> Option<String> {
+        let metadata = vfs::metadata(file).ok()?;
+        if metadata.len() > self.max_file_size {
+            return None;
+        }
+        vfs::read_to_string(file).ok()
+    }
+
+    /// Count `TODO`/`FIXME`/`XXX`/`HACK` markers in all Rust and Python sources within the file
+    /// size limit. Markers must stand alone, so `TODOS` or `xxx_id` are not counted.
+    fn count_debt_markers(&self, path: &Path, files: &ProjectFiles) -> usize {
+        const MARKERS: &[&str] = &["TODO", "FIXME", "XXX", "HACK"];
+
+        files
+            .files
+            .iter()
+            .filter(|(file, size)| {
+                ProjectFiles::has_extension(file, &["rs", "py"]) && *size <= self.max_file_size
+            })
+            .filter_map(|(file, _)| vfs::read_to_string(path.join(file)).ok())
+            .map(|content| {
+                content
+                    .split(|c: char| !c.is_alphanumeric())
+                    .filter(|word| MARKERS.contains(word))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// `.dist-info` directories of the packages installed in `.venv` or `venv`, under
+    /// `lib/pythonX.Y/site-packages` (or `Lib/site-packages` on Windows)
+    fn venv_dist_info_dirs(path: &Path) -> Vec<PathBuf> {
+        let mut site_packages = Vec::new();
+        for venv in [".venv", "venv"] {
+            let venv = path.join(venv);
+            site_packages.push(venv.join("Lib").join("site-packages"));
+            if let Ok(lib_dirs) = vfs::read_dir(venv.join("lib")) {
+                site_packages.extend(lib_dirs.flatten().map(|lib_dir| lib_dir.path().join("site-packages")));
+            }
+        }
+
+        let mut dist_infos: Vec<PathBuf> = site_packages
+            .iter()
+            .filter_map(|dir| vfs::read_dir(dir).ok())
+            .flat_map(|packages| packages.flatten())
+            .filter(|package| package.file_name().to_string_lossy().ends_with(".dist-info"))
+            .map(|package| package.path())
+            .collect();
+        dist_infos.sort();
+        dist_infos.dedup();
+        dist_infos
+    }
+
+    /// Version of `package` installed in the project's virtual environment, from the
+    /// `<name>-<version>.dist-info` directory name
+    fn installed_package_version(path: &Path, package: &str) -> Option<String> {
+        Self::venv_dist_info_dirs(path).iter().find_map(|dist_info| {
+            let name = dist_info.file_name()?.to_str()?.strip_suffix(".dist-info")?;
+            let (name, version) = name.rsplit_once('-')?;
+            matching::normalized_dep_match(name, package).then(|| version.to_string())
+        })
+    }
+
+    /// Collect `(name, license, source)` from vendored crates and installed Python packages,
+    /// reporting those whose license is not permissive
+    fn find_license_warnings(&self, path: &Path) -> Vec<String> {
+        let mut licenses: Vec<(String, String, PathBuf)> = Vec::new();
+
+        // `cargo vendor` keeps each crate's manifest in vendor/<crate>/Cargo.toml
+        if let Ok(entries) = vfs::read_dir(path.join("vendor")) {
+            for entry in entries.flatten() {
+                let manifest = entry.path().join("Cargo.toml");
+                let Some(cargo) = Self::read_toml_table(&manifest) else {
+                    continue;
+                };
+                let package = cargo.get("package");
+                let field = |key: &str| package.and_then(|p| p.get(key)).and_then(|v| v.as_str());
+                if let (Some(name), Some(license)) = (field("name"), field("license")) {
+                    licenses.push((name.to_string(), license.to_string(), manifest.clone()));
+                }
+            }
+        }
+
+        // Installed wheels record their license in their .dist-info/METADATA
+        for dist_info in Self::venv_dist_info_dirs(path) {
+            let metadata = dist_info.join("METADATA");
+            let Some(content) = self.read_within_limit(&metadata) else {
+                continue;
+            };
+            let header = |key: &str| {
+                content
+                    .lines()
+                    .take_while(|line| !line.is_empty())
+                    .find_map(|line| line.strip_prefix(key).map(|v| v.trim().to_string()))
+            };
+            let license = header("License-Expression:")
+                .or_else(|| header("License:"))
+                .filter(|license| !license.is_empty() && license != "UNKNOWN");
+            if let (Some(name), Some(license)) = (header("Name:"), license) {
+                licenses.push((name, license, metadata.clone()));
+            }
+        }
+
+        licenses.sort();
+        licenses
+            .into_iter()
+            .filter(|(_, license, _)| !is_permissive_license(license))
+            .map(|(name, license, source)| {
+                let source = source.strip_prefix(path).unwrap_or(&source).to_path_buf();
+                format!("{} is licensed under {} ({})", name, license, source.display())
+            })
+            .collect()
+    }
+
+    /// Look for ADK callback registrations (`before_model_callback=`, `after_tool_callback=`,
+    /// ...) or functions named like guardrails in Rust and Python sources
+    fn detect_guardrails(&self, files: &ProjectFiles) -> bool {
+        const CALLBACKS: &[&str] = &[
+            "before_model_callback",
+            "after_model_callback",
+            "before_tool_callback",
+            "after_tool_callback",
+            "before_agent_callback",
+            "after_agent_callback",
+        ];
+
+        let registers_callback = |line: &str| {
+            CALLBACKS.iter().any(|callback| {
+                line.match_indices(callback).any(|(start, _)| {
+                    let rest = line[start + callback.len()..].trim_start();
+                    (rest.starts_with('=') && !rest.starts_with("=="))
+                        || rest.starts_with(':')
+                })
+            })
+        };
+        let defines_guardrail = |line: &str| {
+            let words: Vec<&str> = line.split_whitespace().collect();
+            words.windows(2).any(|pair| {
+                matches!(pair[0], "def" | "fn")
+                    && (pair[1].contains("guardrail") || pair[1].contains("safety_check"))
+            })
+        };
+
+        files.with_extensions(&["rs", "py"]).any(|(_, content)| {
+            content
+                .lines()
+                .any(|line| registers_callback(line) || defines_guardrail(line))
+        })
+    }
+
+    /// Check whether Python or Rust sources register a health or readiness route, e.g. FastAPI's
+    /// `@app.get("/health")`, Flask's `@app.route("/readyz")` or axum's `.route("/healthz", ..)`
+    fn detect_health_check(&self, files: &ProjectFiles) -> bool {
+        const HEALTH_PATHS: &[&str] = &[
+            "/health",
+            "/healthz",
+            "/healthcheck",
+            "/ready",
+            "/readyz",
+            "/readiness",
+            "/livez",
+            "/liveness",
+        ];
+        const ROUTE_CALLS: &[&str] = &[
+            ".get(",
+            ".head(",
+            ".route(",
+            ".api_route(",
+            ".add_api_route(",
+            ".add_url_rule(",
+            "#[get(",
+            "web::resource(",
+        ];
+
+        let defines_health_route = |line: &str| {
+            ROUTE_CALLS.iter().any(|call| {
+                line.match_indices(call).any(|(start, _)| {
+                    let rest = line[start + call.len()..].trim_start();
+                    let Some(quote) = rest.chars().next().filter(|c| matches!(c, '"' | '\'')) else {
+                        return false;
+                    };
+                    rest[1..].split(quote).next().is_some_and(|route| {
+                        HEALTH_PATHS.contains(&route.trim_end_matches('/'))
+                    })
+                })
+            })
+        };
+
+        files
+            .with_extensions(&["rs", "py"])
+            .any(|(_, content)| content.lines().any(defines_health_route))
+    }
+
+    /// List what keeps the project from deploying to Agent Engine: it must be a Python ADK
+    /// project with captured dependencies, a module-level `root_agent` to deploy, and a
+    /// Google Cloud project set in `.env` or via `vertexai.init`
+    fn find_agent_engine_blockers(
+        &self,
+        path: &Path,
+        info: &AdkProjectInfo,
+        files: &ProjectFiles,
+    ) -> Vec<String> {
+        let mut blockers = Vec::new();
+
+        if !matches!(info.project_type, AdkProjectType::PythonAdk | AdkProjectType::Mixed) {
+            blockers.push("Agent Engine only deploys Python ADK agents".to_string());
+        }
+        if !info.has_requirements_txt && !vfs::is_file(path.join("pyproject.toml")) {
+            blockers.push("Dependencies are not captured in requirements.txt or pyproject.toml".to_string());
+        }
+
+        let python_sources: Vec<&String> = files
+            .with_extensions(&["py"])
+            .map(|(_, content)| content)
+            .collect();
+        let defines_root_agent = python_sources.iter().any(|content| {
+            content.lines().any(|line| {
+                line.strip_prefix("root_agent").is_some_and(|rest| {
+                    let rest = rest.trim_start();
+                    (rest.starts_with('=') && !rest.starts_with("==")) || rest.starts_with(':')
+                })
+            })
+        });
+        if !defines_root_agent {
+            blockers.push("No deployable agent: define a module-level `root_agent`".to_string());
+        }
+
+        let env_sets_project = vfs::read_to_string(path.join(".env")).is_ok_and(|content| {
+            content.lines().any(|line| {
+                let line = line.trim().trim_start_matches("export ").trim_start();
+                line.strip_prefix("GOOGLE_CLOUD_PROJECT=")
+                    .is_some_and(|value| !value.trim().trim_matches('"').is_empty())
+            })
+        });
+        let init_sets_project = python_sources
+            .iter()
+            .any(|content| content.contains("vertexai.init("));
+        if !env_sets_project && !init_sets_project {
+            blockers.push(
+                "No Google Cloud project configured: set GOOGLE_CLOUD_PROJECT or call vertexai.init"
+                    .to_string(),
+            );
+        }
+
+        blockers
+    }
+
+    /// Map agent tools to the credentials/config they likely need. Tools are the functions
+    /// listed in `tools=[...]` (directly or via `FunctionTool(func=...)`); a tool's needs come
+    /// from environment variables it reads and from the clients it imports, either inside the
+    /// function or at module level when the function uses them. Known clients map through
+    /// [`TOOL_CLIENT_REQUIREMENTS`]; other `*_api`/`*_client`/`*_sdk` modules map to
+    /// `<SERVICE>_API_KEY`. Only tools with requirements are listed, sorted by name.
+    fn find_tool_requirements(&self, files: &ProjectFiles) -> Vec<(String, Vec<String>)> {
+        let sources: Vec<&String> = files
+            .with_extensions(&["py"])
+            .map(|(_, content)| content)
+            .collect();
+
+        let mut tools: Vec<String> = sources.iter().flat_map(|content| Self::listed_tools(content)).collect();
+        tools.sort();
+        tools.dedup();
+
+        let mut requirements = Vec::new();
+        for tool in tools {
+            let mut needs = Vec::new();
+            for content in &sources {
+                let Some(body) = Self::python_function_body(content, &tool) else {
+                    continue;
+                };
+                let module_imports = content
+                    .lines()
+                    .filter(|line| !line.starts_with(char::is_whitespace))
+                    .flat_map(Self::python_imports)
+                    .filter(|(_, bound)| matching::content_contains_token(&body, bound));
+                let body_imports = body.lines().flat_map(|line| Self::python_imports(line.trim()));
+                for (module, bound) in module_imports.chain(body_imports) {
+                    needs.extend(Self::client_requirements(&module, &bound));
+                }
+                needs.extend(Self::env_vars_read(&body));
+            }
+            needs.sort();
+            needs.dedup();
+            if !needs.is_empty() {
+                requirements.push((tool, needs));
+            }
+        }
+
+        requirements
+    }
+
+    /// Function names listed in `tools=[...]` arguments or assignments
+    fn listed_tools(content: &str) -> Vec<String> {
+        let is_identifier = |s: &str| {
+            s.starts_with(|c: char| c.is_alphabetic() || c == '_')
+                && s.chars().all(|c| c.is_alphanumeric() || c == '_')
+        };
+
+        let mut tools = Vec::new();
+        for (start, _) in content.match_indices("tools") {
+            if content[..start].ends_with(|c: char| c.is_alphanumeric() || c == '_') {
+                continue;
+            }
+            let rest = content[start + "tools".len()..].trim_start();
+            let Some(rest) = rest.strip_prefix('=').map(str::trim_start) else {
+                continue;
+            };
+            let Some(list) = rest.strip_prefix('[') else {
+                continue;
+            };
+
+            // Split the list on top-level commas
+            let mut depth = 0;
+            let mut item = String::new();
+            let mut items = Vec::new();
+            for c in list.chars() {
+                match c {
+                    '[' | '(' | '{' => depth += 1,
+                    ']' if depth == 0 => break,
+                    ']' | ')' | '}' => depth -= 1,
+                    ',' if depth == 0 => {
+                        items.push(std::mem::take(&mut item));
+                        continue;
+                    }
+                    _ => {}
+                }
+                item.push(c);
+            }
+            items.push(item);
+
+            for item in items {
+                let item = item.trim();
+                let name = match item.strip_prefix("FunctionTool(") {
+                    Some(args) => args.trim_end_matches(')').trim().trim_start_matches("func=").trim(),
+                    None => item,
+                };
+                if is_identifier(name) {
+                    tools.push(name.to_string());
+                }
+            }
+        }
+        tools
+    }
+
+    /// Source of the indented body of `def name(...)`, if defined in `content`
+    fn python_function_body(content: &str, name: &str) -> Option<String> {
+        let mut lines = content.lines();
+        let def_indent = loop {
+            let line = lines.next()?;
+            let trimmed = line.trim_start();
+            let signature = trimmed.strip_prefix("async ").unwrap_or(trimmed);
+            if signature
+                .strip_prefix("def ")
+                .and_then(|rest| rest.strip_prefix(name))
+                .is_some_and(|rest| rest.trim_start().starts_with('('))
+            {
+                break line.len() - trimmed.len();
+            }
+        };
+
+        let body: Vec<&str> = lines
+            .take_while(|line| line.trim().is_empty() || line.len() - line.trim_start().len() > def_indent)
+            .collect();
+        Some(body.join("\n"))
+    }
+
+    /// (module, bound name) pairs imported by an `import` or `from ... import` line
+    fn python_imports(line: &str) -> Vec<(String, String)> {
+        let bound_name = |spec: &str| {
+            let spec = spec.trim();
+            match spec.split_once(" as ") {
+                Some((_, alias)) => alias.trim().to_string(),
+                None => spec.split('.').next().unwrap_or(spec).to_string(),
+            }
+        };
+
+        if let Some(rest) = line.strip_prefix("from ") {
+            let Some((module, names)) = rest.split_once(" import ") else {
+                return Vec::new();
+            };
+            names
+                .trim_matches(|c| c == '(' || c == ')' || c == ' ')
+                .split(',')
+                .filter(|name| !name.trim().is_empty())
+                .map(|name| (module.trim().to_string(), bound_name(name)))
+                .collect()
+        } else if let Some(rest) = line.strip_prefix("import ") {
+            rest.split(',')
+                .map(|spec| {
+                    let module = spec.trim().split(" as ").next().unwrap_or_default().trim();
+                    (module.to_string(), bound_name(spec))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Likely configuration needed by code using `module`
+    fn client_requirements(module: &str, bound: &str) -> Vec<String> {
+        let known = TOOL_CLIENT_REQUIREMENTS.iter().find(|(client, _)| {
+            module == *client || module.starts_with(&format!("{}.", client))
+        });
+        if let Some((_, needs)) = known {
+            return needs.iter().map(|need| need.to_string()).collect();
+        }
+
+        let root = module.split('.').next().unwrap_or(module).to_lowercase();
+        let service = ["_api", "_client", "_sdk", "api", "client", "sdk"]
+            .iter()
+            .find_map(|suffix| root.strip_suffix(suffix))
+            .or_else(|| bound.ends_with("Client").then_some(root.as_str()))
+            .map(|service| service.trim_end_matches('_'));
+        match service {
+            Some(service) if !service.is_empty() && !service.starts_with("google") => {
+                vec![format!("{}_API_KEY", service.to_uppercase())]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Environment variables read via `os.environ[...]`, `os.environ.get(...)` or `os.getenv(...)`
+    fn env_vars_read(body: &str) -> Vec<String> {
+        let mut vars = Vec::new();
+        for accessor in ["os.environ[", "os.environ.get(", "os.getenv("] {
+            for (start, _) in body.match_indices(accessor) {
+                let rest = body[start + accessor.len()..].trim_start();
+                let Some(quote) = rest.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+                    continue;
+                };
+                if let Some((name, _)) = rest[1..].split_once(quote) {
+                    vars.push(name.to_string());
+                }
+            }
+        }
+        vars
+    }
+
+    /// Find the session backend from ADK session service classes in Rust/Python sources and
+    /// session service URIs in `.env` files. Persistent backends win over `in_memory`, which
+    /// is often only used by tests.
+    fn detect_session_backend(&self, files: &ProjectFiles) -> Option<String> {
+        let markers = [
+            ("database", &["DatabaseSessionService", "sqlite://", "postgresql://", "mysql://"][..]),
+            ("vertex_ai", &["VertexAiSessionService", "agentengine://"][..]),
+            ("in_memory", &["InMemorySessionService", "InMemoryRunner"][..]),
+        ];
+
+        let contents = Self::backend_sources(files, &[]);
+        Self::first_matching_backend(&markers, &contents)
+    }
+
+    /// Find the artifact service backend from ADK artifact service classes and bucket settings
+    /// in sources, `.env` and JSON/YAML/TOML config. GCS wins over `in_memory`.
+    fn detect_artifact_backend(&self, files: &ProjectFiles) -> Option<String> {
+        let markers = [
+            (
+                "gcs",
+                &["GcsArtifactService", "artifact_service_uri=gs://", "ARTIFACT_BUCKET", "artifact_bucket"][..],
+            ),
+            ("in_memory", &["InMemoryArtifactService"][..]),
+        ];
+
+        let contents = Self::backend_sources(files, &["json", "yaml", "yml", "toml"]);
+        Self::first_matching_backend(&markers, &contents)
+    }
+
+    /// Rust/Python sources, `.env` files and files with any of `config_extensions`
+    fn backend_sources<'a>(files: &'a ProjectFiles, config_extensions: &[&str]) -> Vec<&'a str> {
+        files
+            .contents
+            .iter()
+            .filter(|(file, _)| {
+                ProjectFiles::has_extension(file, &["rs", "py"])
+                    || ProjectFiles::is_env_file(file)
+                    || ProjectFiles::has_extension(file, config_extensions)
+            })
+            .map(|(_, content)| content.as_str())
+            .collect()
+    }
+
+    /// First backend, in priority order, with a pattern found in any of `contents`
+    fn first_matching_backend(markers: &[(&str, &[&str])], contents: &[&str]) -> Option<String> {
+        markers.iter().find_map(|(backend, patterns)| {
+            contents
+                .iter()
+                .any(|content| patterns.iter().any(|pattern| content.contains(pattern)))
+                .then(|| backend.to_string())
+        })
+    }
+
+    /// Infer the transport: an explicit `type`/`transport` of a server declared in an MCP
+    /// config takes precedence over rmcp features and streaming dependencies in the manifests
+    fn detect_transport(&self, path: &Path, manifest_paths: &[PathBuf]) -> Option<Transport> {
+        Self::configured_transport(path).or_else(|| self.dependency_transport(path, manifest_paths))
+    }
+
+    /// Transport declared by the `type` or `transport` of a server in `mcp.json` or
+    /// `.kiro/settings/mcp.json`
+    fn configured_transport(path: &Path) -> Option<Transport> {
+        ["mcp.json", ".kiro/settings/mcp.json"].into_iter().find_map(|config| {
+            let content = vfs::read_to_string(path.join(config)).ok()?;
+            let config: McpConfigFile = serde_json::from_str(&content).ok()?;
+            config.mcp_servers.values().find_map(|server| {
+                match server.transport.as_deref()?.to_lowercase().as_str() {
+                    "stdio" => Some(Transport::Stdio),
+                    "sse" => Some(Transport::Sse),
+                    "websocket" | "ws" => Some(Transport::WebSocket),
+                    "streamable-http" | "http" => Some(Transport::Http),
+                    _ => None,
+                }
+            })
+        })
+    }
+
+    /// Transport implied by the enabled rmcp transport features, or failing that by a
+    /// transport-specific dependency
+    fn dependency_transport(&self, path: &Path, manifest_paths: &[PathBuf]) -> Option<Transport> {
+        const RMCP_FEATURES: &[(&str, Transport)] = &[
+            ("transport-io", Transport::Stdio),
+            ("transport-child-process", Transport::Stdio),
+            ("transport-sse", Transport::Sse),
+            ("transport-streamable-http", Transport::Http),
+        ];
+        const DEPENDENCIES: &[(&str, Transport)] = &[
+            ("sse-starlette", Transport::Sse),
+            ("tokio-tungstenite", Transport::WebSocket),
+            ("websockets", Transport::WebSocket),
+        ];
+
+        let cargo_tables: Vec<toml::Table> = manifest_paths
+            .iter()
+            .filter(|manifest| manifest.ends_with("Cargo.toml"))
+            .filter_map(|manifest| Self::read_toml_table(&path.join(manifest)))
+            .collect();
+        let features: Vec<&str> = cargo_tables
+            .iter()
+            .flat_map(|cargo| Self::cargo_dependencies(cargo, true))
+            .filter(|(name, _)| name == "rmcp")
+            .filter_map(|(_, spec)| spec.get("features")?.as_array())
+            .flatten()
+            .filter_map(|feature| feature.as_str())
+            .collect();
+        let from_features = RMCP_FEATURES.iter().find(|(prefix, _)| {
+            features.iter().any(|feature| feature.starts_with(prefix))
+        });
+
+        let declared = self.declared_dependency_names(path, manifest_paths);
+        from_features
+            .or_else(|| {
+                DEPENDENCIES.iter().find(|(dependency, _)| {
+                    declared.iter().any(|name| matching::normalized_dep_match(name, dependency))
+                })
+            })
+            .map(|(_, transport)| *transport)
+    }
+
+    /// Find prompt text files: anything in a `prompts/` or `instructions/` directory, files
+    /// named like `system_prompt.md` or `instructions.txt`, and text files whose name appears
+    /// in a string literal in Rust or Python code (e.g. `open("persona.md")`)
+    fn find_prompt_files(&self, files: &ProjectFiles) -> Vec<PathBuf> {
+        const PROMPT_EXTENSIONS: &[&str] = &["md", "txt", "j2", "jinja", "prompt"];
+        const PROMPT_DIRS: &[&str] = &["prompts", "prompt", "instructions"];
+        // Project docs and manifests that packaging code commonly opens by name
+        const NOT_PROMPTS: &[&str] = &["readme", "changelog", "license", "contributing", "requirements"];
+
+        let is_text = |file: &Path| {
+            file.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| PROMPT_EXTENSIONS.contains(&ext))
+        };
+
+        let sources: Vec<&String> = files
+            .with_extensions(&["rs", "py"])
+            .map(|(_, content)| content)
+            .collect();
+
+        files
+            .paths()
+            .filter(|file| is_text(file))
+            .filter(|file| {
+                let in_prompt_dir = file.parent().is_some_and(|parent| {
+                    parent.components().any(|component| {
+                        component
+                            .as_os_str()
+                            .to_str()
+                            .is_some_and(|name| PROMPT_DIRS.contains(&name))
+                    })
+                });
+                let Some(name) = file.file_name().and_then(|n| n.to_str()) else {
+                    return false;
+                };
+                let stem = name.split('.').next().unwrap_or_default().to_lowercase();
+                if NOT_PROMPTS.iter().any(|doc| stem.starts_with(doc)) {
+                    return false;
+                }
+                let named_like_prompt = stem.contains("prompt") || stem.contains("instruction");
+                let referenced = sources.iter().any(|source| {
+                    source.contains(&format!("\"{}\"", name))
+                        || source.contains(&format!("'{}'", name))
+                        || source.contains(&format!("/{}\"", name))
+                        || source.contains(&format!("/{}'", name))
+                });
+                in_prompt_dir || named_like_prompt || referenced
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Check test files (`test_*.py`, `*_test.py`, `conftest.py`, anything under `tests/`) for
+    /// ADK imports such as `google.adk` or `google_adk`, which also covers `patch("google.adk...")`
+    fn tests_reference_adk(&self, files: &ProjectFiles) -> bool {
+        const ADK_REFERENCES: &[&str] = &["google.adk", "google_adk", "adk_core", "adk_runtime"];
+
+        let is_test_file = |file: &Path| {
+            let Some(name) = file.file_name().and_then(|n| n.to_str()) else {
+                return false;
+            };
+            let in_tests_dir = file
+                .components()
+                .any(|component| matches!(component.as_os_str().to_str(), Some("tests" | "test")));
+            match file.extension().and_then(|ext| ext.to_str()) {
+                Some("py") => {
+                    in_tests_dir
+                        || name.starts_with("test_")
+                        || name.ends_with("_test.py")
+                        || name == "conftest.py"
+                }
+                Some("rs") => in_tests_dir,
+                _ => false,
+            }
+        };
+
+        files
+            .contents
+            .iter()
+            .filter(|(file, _)| is_test_file(file))
+            .any(|(_, content)| {
+                ADK_REFERENCES
+                    .iter()
+                    .any(|reference| matching::content_contains_token(content, reference))
+            })
+    }
+
+    /// Pair generated stubs (`*_pb2.py`, `*_pb2_grpc.py`, `*.pb.rs`) with the `.proto` of the
+    /// same name, preferring one in the same directory
+    fn find_proto_mappings(&self, files: &ProjectFiles) -> Vec<(PathBuf, PathBuf)> {
+        const GENERATED_SUFFIXES: &[&str] = &["_pb2_grpc.py", "_pb2.py", "_pb2.pyi", ".pb.rs"];
+
+        let protos: Vec<&PathBuf> = files
+            .paths()
+            .filter(|file| file.extension().is_some_and(|ext| ext == "proto"))
+            .collect();
+
+        let mut mappings = Vec::new();
+        for file in files.paths() {
+            let Some(name) = file.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            let Some(stem) = GENERATED_SUFFIXES
+                .iter()
+                .find_map(|suffix| name.strip_suffix(suffix))
+            else {
+                continue;
+            };
+
+            let candidates: Vec<&&PathBuf> = protos
+                .iter()
+                .filter(|proto| proto.file_stem().is_some_and(|s| s == stem))
+                .collect();
+            let source = candidates
+                .iter()
+                .find(|proto| proto.parent() == file.parent())
+                .or_else(|| candidates.first());
+            if let Some(source) = source {
+                mappings.push((file.clone(), source.to_path_buf()));
+            }
+        }
+
+        mappings
+    }
+
+    /// Detect deployment pipelines: GitHub Actions workflows that mention a deploy step, and
+    /// Cloud Build configs. Returns whether one was found and the branch that triggers it.
+    fn detect_continuous_deployment(&self, path: &Path) -> (bool, Option<String>) {
+        let mut workflows: Vec<PathBuf> = vfs::read_dir(path.join(".github/workflows"))
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|p| p.extension().is_some_and(|ext| ext == "yml" || ext == "yaml"))
+                    .collect()
+            })
+            .unwrap_or_default();
+        workflows.sort();
+
+        for workflow in workflows {
+            let Ok(content) = vfs::read_to_string(&workflow) else {
+                continue;
+            };
+            if matching::content_contains_token(&content.to_lowercase(), "deploy") {
+                return (true, Self::workflow_push_branch(&content));
+            }
+        }
+
+        for name in ["cloudbuild.yaml", "cloudbuild.yml", "cloudbuild.json"] {
+            if vfs::is_file(path.join(name)) {
+                // Trigger definitions exported with `gcloud builds triggers export` name the branch
+                let branch = ["trigger.yaml", "cloudbuild-trigger.yaml"]
+                    .iter()
+                    .filter_map(|trigger| vfs::read_to_string(path.join(trigger)).ok())
+                    .find_map(|content| {
+                        content.lines().find_map(|line| {
+                            let value = line.trim().strip_prefix("branch:")?;
+                            let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+                            Some(value.trim_start_matches('^').trim_end_matches('$').to_string())
+                        })
+                    });
+                return (true, branch);
+            }
+        }
+
+        (false, None)
+    }
+
+    /// Find the first branch listed under a workflow's `on: push: branches:` trigger
+    fn workflow_push_branch(content: &str) -> Option<String> {
+        let indent = |line: &str| line.len() - line.trim_start().len();
+        let clean = |value: &str| value.trim().trim_matches(|c| c == '"' || c == '\'').to_string();
+        let mut push_indent = None;
+        let mut in_branches = false;
+
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let Some(push) = push_indent else {
+                if trimmed == "push:" {
+                    push_indent = Some(indent(line));
+                }
+                continue;
+            };
+            if indent(line) <= push {
+                return None;
+            }
+
+            if let Some(value) = trimmed.strip_prefix("branches:") {
+                let value = value.trim();
+                if value.is_empty() {
+                    in_branches = true;
+                    continue;
+                }
+                let first = value.trim_start_matches('[').trim_end_matches(']').split(',').next()?;
+                return Some(clean(first));
+            }
+            if in_branches {
+                return trimmed.strip_prefix("- ").map(clean);
+            }
+        }
+        None
+    }
+
+    /// Find CONTRIBUTING, CODEOWNERS, SECURITY and code of conduct files in the locations
+    /// GitHub recognizes, matching names case-insensitively and with any extension
+    fn detect_governance(&self, path: &Path) -> GovernanceInfo {
+        let mut governance = GovernanceInfo::default();
+
+        for dir in ["", ".github", "docs"] {
+            let mut entries: Vec<PathBuf> = match vfs::read_dir(path.join(dir)) {
+                Ok(entries) => entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|entry| vfs::is_file(entry))
+                    .collect(),
+                Err(_) => continue,
+            };
+            entries.sort();
+
+            for entry in entries {
+                let stem = match entry.file_stem().and_then(|s| s.to_str()) {
+                    Some(stem) => stem.to_uppercase(),
+                    None => continue,
+                };
+                let slot = match stem.as_str() {
+                    "CONTRIBUTING" => &mut governance.contributing,
+                    "CODEOWNERS" => &mut governance.codeowners,
+                    "SECURITY" => &mut governance.security_policy,
+                    "CODE_OF_CONDUCT" | "CODE-OF-CONDUCT" => &mut governance.code_of_conduct,
+                    _ => continue,
+                };
+                if slot.is_none() {
+                    *slot = entry.strip_prefix(path).ok().map(|p| p.to_path_buf());
+                }
+            }
+        }
+
+        governance
+    }
+
+    /// Look for an A2A agent card and parse its declared skills and capabilities
+    fn detect_a2a_card(&self, path: &Path) -> DetectionResult<Option<A2aInfo>> {
+        let card_locations = [".well-known/agent.json", "agent.json"];
+
+        for location in &card_locations {
+            let card_path = path.join(location);
+            if !vfs::exists(&card_path) || (!self.strict_parsing && !vfs::is_file(&card_path)) {
+                continue;
+            }
+
+            // Skip unreadable or malformed cards rather than failing detection, unless strict
+            let content = match vfs::read_to_string(&card_path) {
+                Ok(content) => content,
+                Err(source) if self.strict_parsing => {
+                    return Err(DetectionError::UnreadableConfig { path: card_path, source });
+                }
+                Err(_) => continue,
+            };
+            let card: serde_json::Value = match serde_json::from_str(&content) {
+                Ok(card) => card,
+                Err(source) if self.strict_parsing => {
+                    return Err(DetectionError::ParseError {
+                        path: card_path,
+                        source: source.into(),
+                    });
+                }
+                Err(_) => continue,
+            };
+
+            let string_field =
+                |key: &str| card.get(key).and_then(|v| v.as_str()).map(|v| v.to_string());
+
+            let skills = card
+                .get("skills")
+                .and_then(|v| v.as_array())
+                .map(|skills| {
+                    skills
+                        .iter()
+                        .filter_map(|skill| {
+                            skill
+                                .get("id")
+                                .or_else(|| skill.get("name"))
+                                .and_then(|v| v.as_str())
+                                .map(|v| v.to_string())
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let capabilities = card
+                .get("capabilities")
+                .and_then(|v| v.as_object())
+                .map(|capabilities| {
+                    capabilities
+                        .iter()
+                        .filter(|(_, enabled)| enabled.as_bool().unwrap_or(false))
+                        .map(|(name, _)| name.clone())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            return Ok(Some(A2aInfo {
+                name: string_field("name"),
+                version: string_field("version"),
+                url: string_field("url"),
+                card_path,
+                skills,
+                capabilities,
+            }));
+        }
+
+        Ok(None)
+    }
+
+    /// Parse every manifest present as TOML; `None` when there is no manifest to check
+    fn probe_manifests(&self, path: &Path) -> Option<bool> {
+        let mut result = None;
+
+        for manifest in &["Cargo.toml", "pyproject.toml"] {
+            let manifest_path = path.join(manifest);
+            if !vfs::is_file(&manifest_path) {
+                continue;
+            }
+
+            let parses = vfs::read_to_string(&manifest_path)
+                .map(|content| content.parse::<toml::Table>().is_ok())
+                .unwrap_or(false);
+            result = Some(result.unwrap_or(true) && parses);
+        }
+
+        result
+    }
+
+    /// Run a cheap tokenizer over the first entrypoint found; `None` when there is none
+    fn probe_entrypoint(&self, path: &Path) -> Option<bool> {
+        let entrypoints = [
+            ("src/main.rs", SourceLanguage::Rust),
+            ("src/lib.rs", SourceLanguage::Rust),
+            ("main.py", SourceLanguage::Python),
+            ("agent.py", SourceLanguage::Python),
+            ("app.py", SourceLanguage::Python),
+            ("multi_tool_agent/agent.py", SourceLanguage::Python),
+        ];
+
+        let (entrypoint, language) = entrypoints
+            .iter()
+            .find(|(entrypoint, _)| vfs::is_file(path.join(entrypoint)))?;
+
+        let parses = vfs::read_to_string(path.join(entrypoint))
+            .map(|content| tokenizes_cleanly(&content, *language))
+            .unwrap_or(false);
+        Some(parses)
+    }
+
+    /// Cheap first-pass filter that only checks whether marker files exist, without reading
+    /// any file contents. A `Maybe` result still needs [`detect_adk_project`] to confirm.
+    ///
+    /// [`detect_adk_project`]: AdkProjectDetector::detect_adk_project
+    pub fn quick_probe<P: AsRef<Path>>(&self, path: P) -> QuickProbe {
+        let path = path.as_ref();
+        let markers = [
+            "Cargo.toml",
+            "requirements.txt",
+            "pyproject.toml",
+            "setup.py",
+            ".env",
+            ".env.template",
+            "adk.toml",
+            "adk-config.json",
+            "mcp.json",
+            ".kiro/settings/mcp.json",
+            ".well-known/agent.json",
+            "multi_tool_agent",
+            "adk_agents",
+            ".adk-project",
+            ".adk.yaml",
+        ];
+
+        let found: Vec<PathBuf> = markers
+            .iter()
+            .filter(|marker| vfs::exists(path.join(marker)))
+            .map(PathBuf::from)
+            .collect();
+
+        if found.is_empty() {
+            QuickProbe::No
+        } else {
+            QuickProbe::Maybe(found)
+        }
+    }
+
+    /// Read a `.adk-project` or `.adk.yaml` marker. The file may be empty or hold `type`,
+    /// `version` and `owner` as TOML (`type = "python-adk"`) or YAML (`type: python-adk`).
+    fn read_project_marker(&self, path: &Path) -> Option<AdkProjectMarker> {
+        let (name, content) = [".adk-project", ".adk.yaml"]
+            .iter()
+            .find_map(|name| Some((*name, vfs::read_to_string(path.join(name)).ok()?)))?;
+
+        let fields: HashMap<String, String> = match content.parse::<toml::Table>() {
+            Ok(table) => table
+                .into_iter()
+                .filter_map(|(key, value)| Some((key, value.as_str()?.to_string())))
+                .collect(),
+            Err(_) => serde_yaml::from_str::<HashMap<String, serde_yaml::Value>>(&content)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|(key, value)| match value {
+                    serde_yaml::Value::String(value) => Some((key, value)),
+                    serde_yaml::Value::Number(value) => Some((key, value.to_string())),
+                    _ => None,
+                })
+                .collect(),
+        };
+
+        let project_type = fields.get("type").and_then(|declared| {
+            match declared.trim().to_lowercase().replace('_', "-").as_str() {
+                "rust-adk" | "rust" => Some(AdkProjectType::RustAdk),
+                "python-adk" | "python" => Some(AdkProjectType::PythonAdk),
+                "mcp-adk-server" | "mcp-server" | "mcp" => Some(AdkProjectType::McpAdkServer),
+                "mixed" => Some(AdkProjectType::Mixed),
+                _ => None,
+            }
+        });
+
+        Some(AdkProjectMarker {
+            path: PathBuf::from(name),
+            project_type,
+            version: fields.get("version").cloned(),
+            owner: fields.get("owner").cloned(),
+        })
+    }
+
+    /// Find ADK config files (with the marker they contain) and ADK-specific directories.
+    /// Paths are relative to the project root.
+    fn find_adk_config_signals(&self, path: &Path) -> Vec<SignalAttribution> {
+        let mut signals = Vec::new();
+
+        // Common ADK configuration file patterns
+        let adk_config_files = [
+            ".env",
+            ".env.template",
+            "adk.toml",
+            "adk-config.json",
+            "vertex-config.json",
+            "google-cloud-config.json",
+        ];
+
+        for config_file in &adk_config_files {
+            // Check if the config file contains ADK-related content
+            if let Ok(content) = vfs::read_to_string(path.join(config_file)) {
+                let marker = ["GOOGLE_API_KEY", "VERTEXAI", "ADK", "google-genai"]
+                    .into_iter()
+                    .find(|marker| matching::content_contains_token(&content, marker));
+                if let Some(marker) = marker {
+                    signals.push(SignalAttribution {
+                        signal: "config".to_string(),
+                        file: PathBuf::from(config_file),
+                        matched: marker.to_string(),
+                    });
+                }
+            }
+        }
+
+        // Check for ADK-specific directory structures
+        let adk_directories = ["multi_tool_agent", "adk_agents", "src/expert", "src/review"];
+
+        for dir in &adk_directories {
+            if vfs::is_dir(path.join(dir)) {
+                signals.push(SignalAttribution {
+                    signal: "directory".to_string(),
+                    file: PathBuf::from(dir),
+                    matched: dir.rsplit('/').next().unwrap_or(dir).to_string(),
+                });
+            }
+        }
+
+        signals
+    }
+
+    /// List the files under `path` that pass [`Self::should_process_file`], skipping the same
+    /// build/cache and vendored directories as the size estimate. Paths are sorted.
+    pub fn discover_source_files<P: AsRef<Path>>(&self, path: P) -> Result<Vec<PathBuf>> {
+        let path = path.as_ref();
+        let files = self.walk_project(path, &self.find_vendor_dirs(path))?;
+        Ok(self.discover_source_files_in(path, &files))
+    }
+
+    /// The walked `files` that pass [`Self::should_process_file`], joined onto `path`
+    fn discover_source_files_in(&self, path: &Path, files: &[(PathBuf, u64)]) -> Vec<PathBuf> {
+        files
+            .iter()
+            .filter(|(file, size)| self.is_processable(file, *size))
+            .map(|(file, _)| path.join(file))
+            .collect()
+    }
+
+    /// Determine the project type based on collected information, along with the reason
+    /// when it is not an ADK project
+    fn determine_project_type(&self, info: &AdkProjectInfo) -> (AdkProjectType, Option<NoneReason>) {
+        // A marker declaring the type is authoritative
+        if let Some(project_type) = info.marker.as_ref().and_then(|m| m.project_type.clone()) {
+            return (project_type, None);
+        }
+
+        let has_rust = info.has_cargo_toml;
+        let has_python = info.has_requirements_txt || info.has_pyproject_toml;
+        let has_adk = info.has_adk_dependencies || info.has_adk_config || info.marker.is_some();
+
+        if !has_adk {
+            let reason = if !has_rust && !has_python {
+                NoneReason::NoManifest
+            } else if self.has_uncounted_adk_dependencies(info) {
+                NoneReason::BelowSignalThreshold
+            } else {
+                NoneReason::NoAdkDependencies
+            };
+            return (AdkProjectType::None, Some(reason));
+        }
+
+        let project_type = match (has_rust, has_python) {
+            (true, true) => AdkProjectType::Mixed,
+            (true, false) => {
+                // An MCP server declares rmcp, possibly renamed, as a regular dependency
+                let declares_rmcp = info
+                    .manifest_paths
+                    .iter()
+                    .filter(|manifest| manifest.ends_with("Cargo.toml"))
+                    .filter_map(|manifest| Self::read_toml_table(&info.root_path.join(manifest)))
+                    .any(|cargo| {
+                        let dependencies = Self::cargo_dependencies(&cargo, false);
+                        dependencies.iter().any(|(name, _)| name == "rmcp")
+                    });
+                if declares_rmcp {
+                    AdkProjectType::McpAdkServer
+                } else {
+                    AdkProjectType::RustAdk
+                }
+            }
+            (false, true) => AdkProjectType::PythonAdk,
+            // Has ADK config or a marker but no clear language indicators; default to Python
+            (false, false) => AdkProjectType::PythonAdk,
+        };
+        (project_type, None)
+    }
+
+    /// Whether ADK dependencies are declared only where they are not counted, i.e. among dev
+    /// dependencies while dev dependencies are not considered
+    fn has_uncounted_adk_dependencies(&self, info: &AdkProjectInfo) -> bool {
+        if self.consider_dev_deps {
+            return false;
+        }
+        let counting_dev = self.clone().with_consider_dev_deps(true);
+        info.manifest_paths.iter().any(|manifest| {
+            if manifest.ends_with("Cargo.toml") {
+                vfs::read_to_string(info.root_path.join(manifest)).is_ok_and(|content| {
+                    !counting_dev.detect_rust_adk_dependencies(&content).is_empty()
+                })
+            } else if manifest.ends_with("pyproject.toml") {
+                !counting_dev.matched_pyproject_adk_dependencies(&info.root_path).is_empty()
+            } else {
+                false
+            }
+        })
+    }
+
+    /// Check if a specific file should be processed based on size and type
+    pub fn should_process_file<P: AsRef<Path>>(&self, file_path: P) -> Result<bool> {
         let file_path = file_path.as_ref();
+        self.is_processable_file(file_path)
+            .with_context(|| format!("Failed to get metadata for {:?}", file_path))
+    }
+
+    fn is_processable_file(&self, file_path: &Path) -> std::io::Result<bool> {
+        if !vfs::exists(file_path) {
+            return Ok(false);
+        }
+
+        let metadata = vfs::metadata(file_path)?;
+        Ok(self.is_processable(file_path, metadata.len()))
+    }
+
+    /// Whether a file of `size` bytes is within the size limit and of a relevant type
+    fn is_processable(&self, file_path: &Path, size: u64) -> bool {
+        // Check file size
+        if size > self.max_file_size {
+            return false;
+        }
+
+        // Check file extension for relevant types
+        if let Some(extension) = file_path.extension().and_then(|ext| ext.to_str()) {
+            matches!(extension, "rs" | "py" | "toml" | "json" | "yaml" | "yml" | "md")
+        } else {
+            // Files without extensions - check specific names
+            if let Some(filename) = file_path.file_name().and_then(|name| name.to_str()) {
+                matches!(
+                    filename,
+                    "Cargo.toml" | "requirements.txt" | "setup.py" | ".env" | ".env.template"
+                )
+            } else {
+                false
+            }
+        }
+    }
+
+    /// Get a list of ADK projects in a directory tree
+    pub fn find_adk_projects<P: AsRef<Path>>(&self, root_path: P) -> Result<Vec<AdkProjectInfo>> {
+        let root_path = root_path.as_ref();
+        let mut projects = Vec::new();
+
+        fn search_directory(
+            detector: &AdkProjectDetector,
+            dir: &Path,
+            projects: &mut Vec<AdkProjectInfo>,
+            // Workspace members already detected and skipped submodules
+            handled_dirs: &mut Vec<PathBuf>,
+            max_depth: usize,
+            current_depth: usize,
+        ) -> Result<()> {
+            if current_depth >= max_depth || handled_dirs.iter().any(|member| member == dir) {
+                return Ok(());
+            }
+
+            // A Cargo workspace reports each ADK member crate; the rest of the tree is still
+            // searched, skipping the members
+            if let Some((members, is_virtual)) = AdkProjectDetector::read_cargo_workspace(dir) {
+                if !is_virtual {
+                    if let Ok(project_info) = detector.detect_adk_project(dir) {
+                        if project_info.project_type != AdkProjectType::None {
+                            projects.push(project_info);
+                        }
+                    }
+                }
+                for member in members {
+                    if let Ok(project_info) = detector.detect_adk_project(&member) {
+                        if project_info.project_type != AdkProjectType::None {
+                            projects.push(project_info);
+                        }
+                    }
+                    handled_dirs.push(member);
+                }
+            } else {
+                // Check if current directory is an ADK project
+                match detector.detect_adk_project(dir) {
+                    Ok(project_info) => {
+                        if project_info.project_type != AdkProjectType::None {
+                            projects.push(project_info);
+                            return Ok(()); // Don't search subdirectories of detected projects
+                        }
+                    }
+                    Err(_) => {
+                        // Continue searching even if detection fails for this directory
+                    }
+                }
+            }
+
+            // Search subdirectories
+            if let Ok(entries) = vfs::read_dir(dir) {
+                for entry in entries.flatten() {
+                    let path = entry.path();
+                    if vfs::is_dir(&path) {
+                        // Skip build/cache directories
+                        if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+                            if detector.is_skipped_dir(name) {
+                                continue;
+                            }
+                        }
+                        search_directory(
+                            detector,
+                            &path,
+                            projects,
+                            handled_dirs,
+                            max_depth,
+                            current_depth + 1,
+                        )?;
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        let submodules = Self::read_gitmodules(root_path);
+        let mut handled_dirs: Vec<PathBuf> = Vec::new();
+        if self.skip_submodules {
+            handled_dirs.extend(submodules.iter().map(|submodule| root_path.join(submodule)));
+        }
+        search_directory(self, root_path, &mut projects, &mut handled_dirs, self.max_depth, 0)?;
+
+        for project in &mut projects {
+            project.submodule_path = submodules
+                .iter()
+                .find(|submodule| project.root_path.starts_with(root_path.join(submodule)))
+                .cloned();
+            project.in_submodule = project.submodule_path.is_some();
+        }
+
+        // read_dir order is platform-dependent, so sort for deterministic output
+        projects.sort_by_cached_key(|project| {
+            vfs::canonicalize(&project.root_path).unwrap_or_else(|_| project.root_path.clone())
+        });
+        Ok(projects)
+    }
+
+    /// Submodule paths declared by `root/.gitmodules`, relative to the root
+    fn read_gitmodules(root: &Path) -> Vec<PathBuf> {
+        let Ok(content) = vfs::read_to_string(root.join(".gitmodules")) else {
+            return Vec::new();
+        };
+        content
+            .lines()
+            .filter_map(|line| {
+                let (key, value) = line.split_once('=')?;
+                (key.trim() == "path").then(|| PathBuf::from(value.trim().trim_matches('"')))
+            })
+            .collect()
+    }
+
+    /// Read the `[workspace]` table of `dir/Cargo.toml`: the member crate directories (glob
+    /// `members` expanded, `exclude` applied) and whether the manifest is virtual, i.e. has
+    /// no `[package]`. `None` if there is no workspace.
+    fn read_cargo_workspace(dir: &Path) -> Option<(Vec<PathBuf>, bool)> {
+        let manifest = Self::read_toml_table(&dir.join("Cargo.toml"))?;
+        let workspace = manifest.get("workspace")?;
+        let paths = |key: &str| -> Vec<&str> {
+            workspace
+                .get(key)
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|v| v.as_str())
+                .collect()
+        };
+        // `./crates/a` and `crates/a` name the same member
+        let normalize = |path: &Path| -> PathBuf {
+            path.components()
+                .filter(|component| !matches!(component, std::path::Component::CurDir))
+                .collect()
+        };
+
+        let excluded: Vec<PathBuf> = paths("exclude")
+            .into_iter()
+            .map(|path| dir.join(normalize(Path::new(path))))
+            .collect();
+        // Only the member part is a pattern; `[` or `*` in the checkout path are literal
+        let prefix = glob::Pattern::escape(&dir.to_string_lossy());
+        let mut members = Vec::new();
+        for pattern in paths("members") {
+            let pattern = format!("{}/{}", prefix, normalize(Path::new(pattern)).to_string_lossy());
+            let Ok(matches) = glob::glob(&pattern) else {
+                continue;
+            };
+            for member in matches.flatten() {
+                if vfs::is_file(member.join("Cargo.toml"))
+                    && !excluded.contains(&member)
+                    && !members.contains(&member)
+                {
+                    members.push(member);
+                }
+            }
+        }
+        members.sort();
+
+        Some((members, !manifest.contains_key("package")))
+    }
+
+    /// Find ADK projects using `config` for this call only; the detector is left unchanged
+    pub fn find_adk_projects_with<P: AsRef<Path>>(
+        &self,
+        root_path: P,
+        config: &crate::DetectionConfig,
+    ) -> Result<Vec<AdkProjectInfo>> {
+        self.with_config(config).find_adk_projects(root_path)
+    }
+}
+
+/// Resolve a Cargo version requirement to a representative version.
+///
+/// Exact versions (`1.2.3`, `=1.2.3`) resolve to themselves. Ranges resolve to the lowest
+/// version they accept: `^1.2` gives `1.2.0`, `>=1.0, <2` gives `1.0.0` and `>1.4.2` gives
+/// `1.4.3`. Requirements without a lower bound (`<2`, `*`) and unparsable input give `None`.
+pub fn normalize_version_req(requirement: &str) -> Option<semver::Version> {
+    use semver::{Op, Version, VersionReq};
+
+    let requirement = requirement.trim();
+    if let Ok(version) = Version::parse(requirement) {
+        return Some(version);
+    }
+
+    let requirement = VersionReq::parse(requirement).ok()?;
+    requirement
+        .comparators
+        .iter()
+        .filter_map(|comparator| {
+            let mut version = Version::new(
+                comparator.major,
+                comparator.minor.unwrap_or(0),
+                comparator.patch.unwrap_or(0),
+            );
+            version.pre = comparator.pre.clone();
+            match comparator.op {
+                Op::Exact | Op::GreaterEq | Op::Caret | Op::Tilde | Op::Wildcard => Some(version),
+                Op::Greater => Some(match (comparator.minor, comparator.patch) {
+                    (Some(_), Some(_)) => Version::new(version.major, version.minor, version.patch + 1),
+                    (Some(_), None) => Version::new(version.major, version.minor + 1, 0),
+                    _ => Version::new(version.major + 1, 0, 0),
+                }),
+                _ => None,
+            }
+        })
+        .max()
+}
+
+/// Major version a Cargo or pip requirement pins to: the lowest accepted version for Cargo
+/// ranges, or the version of the first `==`, `~=`, `>=` or `^` clause for pip specifiers
+fn requirement_major(requirement: &str) -> Option<u64> {
+    if let Some(version) = normalize_version_req(requirement) {
+        return Some(version.major);
+    }
+    requirement.split(',').find_map(|clause| {
+        let version = ["===", "==", "~=", ">=", "^", "~"]
+            .iter()
+            .find_map(|op| clause.trim().strip_prefix(op))?;
+        let digits: String = version.trim().chars().take_while(|c| c.is_ascii_digit()).collect();
+        digits.parse().ok()
+    })
+}
+
+/// Check whether an SPDX-style license expression can be satisfied with permissive licenses
+/// only. `OR` (or `/`) alternatives need one permissive choice; `AND` parts must all be.
+fn is_permissive_license(expression: &str) -> bool {
+    const PERMISSIVE: &[&str] = &[
+        "MIT", "MIT-0", "APACHE-2.0", "APACHE 2.0", "APACHE SOFTWARE LICENSE", "BSD", "BSD-2-CLAUSE",
+        "BSD-3-CLAUSE", "0BSD", "ISC", "ZLIB", "UNLICENSE", "CC0-1.0", "BSL-1.0", "PSF-2.0",
+        "PYTHON-2.0", "UNICODE-3.0", "UNICODE-DFS-2016",
+    ];
+
+    let expression = expression.to_uppercase().replace(['(', ')'], " ");
+    expression.split(" OR ").flat_map(|part| part.split('/')).any(|alternative| {
+        alternative.split(" AND ").all(|license| {
+            let license = license.trim();
+            let license = license.split(" WITH ").next().unwrap_or(license).trim();
+            PERMISSIVE.contains(&license)
+        })
+    })
+}
+
+/// Check whether a Cargo or PEP 440 version requirement is a wildcard (`*`, `1.*`), has only
+/// a lower bound (`>=1.0`, `>1`), or is missing entirely
+fn is_loose_requirement(requirement: &str) -> bool {
+    let requirement = requirement.trim();
+    if requirement.is_empty() || requirement.contains('*') {
+        return true;
+    }
+
+    let clauses: Vec<&str> = requirement.split(',').map(str::trim).collect();
+    let has_lower = clauses.iter().any(|clause| clause.starts_with('>'));
+    let has_upper = clauses
+        .iter()
+        .any(|clause| clause.starts_with('<') || clause.starts_with("~=") || clause.starts_with("=="));
+    has_lower && !has_upper
+}
+
+/// Whether the lowest version accepted by `requirement` (see [`normalize_version_req`]) is
+/// below `minimum`; unparsable input is never reported as below
+fn version_below(requirement: &str, minimum: &str) -> bool {
+    match (normalize_version_req(requirement), normalize_version_req(minimum)) {
+        (Some(version), Some(minimum)) => version < minimum,
+        _ => false,
+    }
+}
+
+/// Source languages understood by the syntax probe tokenizer
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SourceLanguage {
+    Rust,
+    Python,
+}
+
+/// Scan source text for balanced delimiters and terminated strings/comments.
+///
+/// This only tokenizes; it does not parse grammar, so syntactically invalid code with
+/// balanced brackets still passes.
+fn tokenizes_cleanly(content: &str, language: SourceLanguage) -> bool {
+    let chars: Vec<char> = content.chars().collect();
+    let mut delimiters = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        match (language, c) {
+            // Line comments
+            (SourceLanguage::Rust, '/') if next == Some('/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            (SourceLanguage::Python, '#') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            // Rust block comments nest
+            (SourceLanguage::Rust, '/') if next == Some('*') => {
+                let mut depth = 1;
+                i += 2;
+                while depth > 0 {
+                    match (chars.get(i), chars.get(i + 1)) {
+                        (Some('/'), Some('*')) => {
+                            depth += 1;
+                            i += 2;
+                        }
+                        (Some('*'), Some('/')) => {
+                            depth -= 1;
+                            i += 2;
+                        }
+                        (Some(_), _) => i += 1,
+                        (None, _) => return false,
+                    }
+                }
+                continue;
+            }
+            // Rust raw strings: r"..", r#".."#, br".."
+            (SourceLanguage::Rust, 'r') if starts_raw_string_prefix(&chars, i) => {
+                let mut hashes = 0;
+                while chars.get(i + 1 + hashes) == Some(&'#') {
+                    hashes += 1;
+                }
+                if chars.get(i + 1 + hashes) == Some(&'"') {
+                    i += hashes + 2;
+                    loop {
+                        match chars.get(i) {
+                            Some('"') if (1..=hashes).all(|h| chars.get(i + h) == Some(&'#')) => {
+                                i += hashes + 1;
+                                break;
+                            }
+                            Some(_) => i += 1,
+                            None => return false,
+                        }
+                    }
+                    continue;
+                }
+            }
+            // Rust char literals vs lifetimes
+            (SourceLanguage::Rust, '\'') => {
+                if next == Some('\\') {
+                    i += 2;
+                    while chars.get(i).is_some_and(|&ch| ch != '\'' && ch != '\n') {
+                        i += 1;
+                    }
+                    if chars.get(i) != Some(&'\'') {
+                        return false;
+                    }
+                } else if chars.get(i + 2) == Some(&'\'') {
+                    i += 2;
+                }
+                i += 1;
+                continue;
+            }
+            // Python strings, including triple-quoted ones
+            (SourceLanguage::Python, '"' | '\'') => {
+                let triple = next == Some(c) && chars.get(i + 2) == Some(&c);
+                i += if triple { 3 } else { 1 };
+                loop {
+                    match chars.get(i) {
+                        Some('\\') => i += 2,
+                        Some(&ch) if ch == c => {
+                            if !triple {
+                                i += 1;
+                                break;
+                            }
+                            if chars.get(i + 1) == Some(&c) && chars.get(i + 2) == Some(&c) {
+                                i += 3;
+                                break;
+                            }
+                            i += 1;
+                        }
+                        Some('\n') if !triple => return false,
+                        Some(_) => i += 1,
+                        None => return false,
+                    }
+                }
+                continue;
+            }
+            // Rust strings may span lines
+            (SourceLanguage::Rust, '"') => {
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        Some('\\') => i += 2,
+                        Some('"') => {
+                            i += 1;
+                            break;
+                        }
+                        Some(_) => i += 1,
+                        None => return false,
+                    }
+                }
+                continue;
+            }
+            (_, '(' | '[' | '{') => delimiters.push(c),
+            (_, ')' | ']' | '}') => {
+                let expected = match c {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+                if delimiters.pop() != Some(expected) {
+                    return false;
+                }
+            }
+            _ => {}
+        }
+
+        i += 1;
+    }
+
+    delimiters.is_empty()
+}
+
+/// Whether the `r` at `index` begins a raw string prefix rather than sitting inside an identifier
+fn starts_raw_string_prefix(chars: &[char], index: usize) -> bool {
+    let is_ident = |ch: char| ch.is_alphanumeric() || ch == '_';
+
+    match index {
+        0 => true,
+        1 => chars[0] == 'b' || !is_ident(chars[0]),
+        _ if chars[index - 1] == 'b' => !is_ident(chars[index - 2]),
+        _ => !is_ident(chars[index - 1]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_detect_rust_adk_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let cargo_content = r#"
+[package]
+name = "test-adk"
+version = "0.1.0"
+
+[dependencies]
+google-adk = "1.0"
+tokio = "1.0"
+"#;
+
+        fs::write(temp_dir.path().join("Cargo.toml"), cargo_content).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(result.project_type, AdkProjectType::RustAdk);
+        assert!(result.has_cargo_toml);
+        assert!(result.has_adk_dependencies);
+    }
+
+    #[test]
+    fn test_is_adk_and_is_empty() {
+        let temp_dir = TempDir::new().unwrap();
+        let detector = AdkProjectDetector::default();
+
+        let empty = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert!(!empty.is_adk());
+        assert!(empty.is_empty());
+
+        // A plain crate is not ADK but is not empty either
+        fs::write(temp_dir.path().join("Cargo.toml"), "[package]\nname = \"plain\"\n").unwrap();
+        let plain = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert!(!plain.is_adk());
+        assert!(!plain.is_empty());
+
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"agent\"\n\n[dependencies]\ngoogle-adk = \"1.0\"\n",
+        )
+        .unwrap();
+        let adk = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert!(adk.is_adk());
+        assert!(!adk.is_empty());
+    }
+
+    #[test]
+    fn test_google_dep_mismatch() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("requirements.txt"),
+            "google-adk==1.2.0\ngoogle-genai==0.8.0\ngoogle-cloud-aiplatform>=1.38,<2\n",
+        )
+        .unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(
+            result.google_dep_mismatches,
+            vec!["google-genai 0.x in requirements.txt is incompatible with google-adk 1.x (expects 1.x)"]
+        );
+    }
+
+    #[test]
+    fn test_agent_engine_readiness() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("requirements.txt"), "google-adk==1.2.0\n").unwrap();
+        fs::write(temp_dir.path().join(".env"), "GOOGLE_CLOUD_PROJECT=my-project\n").unwrap();
+        fs::create_dir(temp_dir.path().join("my_agent")).unwrap();
+        fs::write(
+            temp_dir.path().join("my_agent/agent.py"),
+            "from google.adk.agents import Agent\n\nagent = Agent(name=\"helper\")\n",
+        )
+        .unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert!(!result.agent_engine_ready);
+        assert_eq!(
+            result.agent_engine_blockers,
+            vec!["No deployable agent: define a module-level `root_agent`"]
+        );
+
+        fs::write(
+            temp_dir.path().join("my_agent/agent.py"),
+            "from google.adk.agents import Agent\n\nroot_agent = Agent(name=\"helper\")\n",
+        )
+        .unwrap();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert!(result.agent_engine_ready);
+    }
+
+    #[test]
+    fn test_source_scan_scope_and_budget() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("requirements.txt"), "google-adk==1.2.0\n").unwrap();
+        fs::create_dir_all(temp_dir.path().join("docs/examples")).unwrap();
+        fs::write(
+            temp_dir.path().join("docs/examples/guarded.py"),
+            "agent = Agent(before_model_callback=check)\n",
+        )
+        .unwrap();
+
+        // Sources outside the agent, config and test directories are not read
+        let detector = AdkProjectDetector::default();
+        assert!(!detector.detect_adk_project(temp_dir.path()).unwrap().has_guardrails);
+
+        fs::create_dir(temp_dir.path().join("src")).unwrap();
+        fs::write(
+            temp_dir.path().join("src/agent.py"),
+            "agent = Agent(before_model_callback=check)\n",
+        )
+        .unwrap();
+        assert!(detector.detect_adk_project(temp_dir.path()).unwrap().has_guardrails);
+
+        // Once the budget is spent no more sources are read
+        let detector = AdkProjectDetector::default().with_source_scan_budget(10);
+        assert!(!detector.detect_adk_project(temp_dir.path()).unwrap().has_guardrails);
+
+        // A symlinked directory cycle is not followed
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(temp_dir.path(), temp_dir.path().join("src/loop")).unwrap();
+            let result = AdkProjectDetector::default().detect_adk_project(temp_dir.path()).unwrap();
+            assert!(result.has_guardrails);
+        }
+    }
+
+    #[test]
+    fn test_detect_web_framework() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("requirements.txt"),
+            "google-adk==1.2.0\nFastAPI>=0.110\nuvicorn==0.29.0\n",
+        )
+        .unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.web_framework.as_deref(), Some("fastapi"));
+
+        fs::write(temp_dir.path().join("requirements.txt"), "google-adk==1.2.0\n").unwrap();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.web_framework, None);
+    }
+
+    #[test]
+    fn test_duplicate_dependencies() {
+        let temp_dir = TempDir::new().unwrap();
+        let cargo_content = r#"
+[package]
+name = "adk-agent"
+version = "0.1.0"
+
+[dependencies]
+google-adk = "1.0"
+tokio = { version = "1", features = ["rt"] }
+
+[dev-dependencies]
+google-adk = { version = "0.9", features = ["testing"] }
+tokio = { version = "1", features = ["full"] }
+"#;
+        fs::write(temp_dir.path().join("Cargo.toml"), cargo_content).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        // tokio only adds features in dev-dependencies, which is not a duplicate
+        assert_eq!(
+            result.duplicate_dependencies,
+            vec![r#"google-adk is declared in [dependencies] as "1.0" and in [dev-dependencies] as "0.9" (Cargo.toml)"#]
+        );
+    }
+
+    #[test]
+    fn test_stale_lockfile() {
+        let temp_dir = TempDir::new().unwrap();
+        let cargo_content = r#"
+[package]
+name = "adk-agent"
+version = "0.1.0"
+
+[dependencies]
+google-adk = "1.0"
+http-client = { package = "reqwest", version = "0.12" }
+"#;
+        let lock_content = r#"
+version = 3
+
+[[package]]
+name = "adk-agent"
+version = "0.1.0"
+
+[[package]]
+name = "google-adk"
+version = "1.0.0"
+"#;
+        fs::write(temp_dir.path().join("Cargo.toml"), cargo_content).unwrap();
+        fs::write(temp_dir.path().join("Cargo.lock"), lock_content).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert!(result.lockfile_stale);
+        assert_eq!(result.lockfile_missing, vec!["reqwest"]);
+
+        // Poetry projects compare against poetry.lock, ignoring the python constraint
+        let poetry_dir = TempDir::new().unwrap();
+        let pyproject = "[tool.poetry.dependencies]\npython = \"^3.11\"\ngoogle-adk = \"^1.0\"\n";
+        fs::write(poetry_dir.path().join("pyproject.toml"), pyproject).unwrap();
+        fs::write(poetry_dir.path().join("poetry.lock"), "[[package]]\nname = \"google-adk\"\nversion = \"1.2.0\"\n").unwrap();
+        let result = detector.detect_adk_project(poetry_dir.path()).unwrap();
+        assert!(!result.lockfile_stale);
+    }
+
+    #[test]
+    fn test_detect_pyproject_adk_dependencies() {
+        let detector = AdkProjectDetector::default();
+
+        let pep621_dir = TempDir::new().unwrap();
+        let pep621 = r#"
+[project]
+name = "weather-agent"
+dependencies = ["google-adk>=1.0", "httpx"]
+"#;
+        fs::write(pep621_dir.path().join("pyproject.toml"), pep621).unwrap();
+        let result = detector.detect_adk_project(pep621_dir.path()).unwrap();
+        assert_eq!(result.project_type, AdkProjectType::PythonAdk);
+        assert!(result.has_pyproject_toml);
+        assert!(result.has_adk_dependencies);
+        assert!(!result.uses_uv);
+
+        let poetry_dir = TempDir::new().unwrap();
+        let poetry = r#"
+[tool.poetry]
+name = "weather-agent"
+
+[tool.poetry.dependencies]
+python = "^3.11"
+google-adk = "^1.0"
+"#;
+        fs::write(poetry_dir.path().join("pyproject.toml"), poetry).unwrap();
+        let result = detector.detect_adk_project(poetry_dir.path()).unwrap();
+        assert_eq!(result.project_type, AdkProjectType::PythonAdk);
+        assert!(result.has_adk_dependencies);
+        assert_eq!(result.signal_attribution[0].file, PathBuf::from("pyproject.toml"));
+    }
+
+    #[test]
+    fn test_infer_rag_category() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("requirements.txt"),
+            "google-adk==1.0.0\nchromadb>=0.5\nrequests\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("README.md"),
+            "# Docs agent\n\nAnswers questions using retrieval over the product manual.\n",
+        )
+        .unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.inferred_category.as_deref(), Some("rag"));
+
+        // A lone README keyword is too weak a signal
+        let plain_dir = TempDir::new().unwrap();
+        fs::write(plain_dir.path().join("requirements.txt"), "google-adk==1.0.0\n").unwrap();
+        fs::write(plain_dir.path().join("README.md"), "A chat demo.\n").unwrap();
+        let result = detector.detect_adk_project(plain_dir.path()).unwrap();
+        assert_eq!(result.inferred_category, None);
+    }
+
+    #[test]
+    fn test_discover_source_files() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("src")).unwrap();
+        fs::create_dir_all(temp_dir.path().join("target/debug")).unwrap();
+        fs::write(temp_dir.path().join("Cargo.toml"), "[dependencies]\ngoogle-adk = \"1.0\"\n").unwrap();
+        fs::write(temp_dir.path().join("src/main.rs"), "fn main() {}").unwrap();
+        fs::write(temp_dir.path().join("src/data.json"), "x".repeat(2048)).unwrap();
+        fs::write(temp_dir.path().join("target/debug/build.rs"), "fn main() {}").unwrap();
+        fs::write(temp_dir.path().join("logo.png"), "png").unwrap();
+
+        let detector = AdkProjectDetector::new(1024);
+        let expected = vec![temp_dir.path().join("Cargo.toml"), temp_dir.path().join("src/main.rs")];
+        assert_eq!(detector.discover_source_files(temp_dir.path()).unwrap(), expected);
+
+        // Only listed on the detection result when enabled
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert!(result.source_files.is_empty());
+        let result = detector.with_source_files(true).detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.source_files, expected);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_detect_adk_project_async_matches_sync() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join("src")).unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"agent\"\nversion = \"0.1.0\"\n\n[dependencies]\ngoogle-adk = \"1.0\"\n",
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("src/main.rs"), "fn main() {}").unwrap();
+        fs::write(temp_dir.path().join(".env"), "GOOGLE_API_KEY=abc\n").unwrap();
+
+        fs::create_dir(temp_dir.path().join("config")).unwrap();
+        fs::write(temp_dir.path().join("config/adk.yaml"), "model: gemini-2.0-flash\n").unwrap();
+
+        fn assert_send<T: Send>(value: T) -> T {
+            value
+        }
+        let detector = AdkProjectDetector::default();
+        let sync_info = detector.detect_adk_project(temp_dir.path()).unwrap();
+        let async_info =
+            assert_send(detector.detect_adk_project_async(temp_dir.path())).await.unwrap();
+        assert_eq!(async_info.project_type, AdkProjectType::RustAdk);
+        assert_eq!(
+            serde_json::to_value(&async_info).unwrap(),
+            serde_json::to_value(&sync_info).unwrap()
+        );
+
+        let config_detector = crate::detection::config_detector::AdkConfigDetector::default();
+        let sync_config = config_detector.detect_adk_config(temp_dir.path()).unwrap();
+        let async_config = config_detector.detect_adk_config_async(temp_dir.path()).await.unwrap();
+        assert_eq!(
+            serde_json::to_value(&async_config).unwrap(),
+            serde_json::to_value(&sync_config).unwrap()
+        );
+
+        let validator = FileValidator::default();
+        let main_rs = temp_dir.path().join("src/main.rs");
+        let validation = validator.validate_file_async(&main_rs).await.unwrap();
+        assert!(validation.is_valid);
+        assert_eq!(validation.file_size, validator.validate_file(&main_rs).unwrap().file_size);
+
+        let missing = temp_dir.path().join("missing");
+        assert!(matches!(
+            detector.detect_adk_project_async(&missing).await,
+            Err(DetectionError::PathNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_detect_python_adk_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let requirements_content = "google-adk==1.0.0\nrequests==2.28.0";
+
+        fs::write(
+            temp_dir.path().join("requirements.txt"),
+            requirements_content,
+        )
+        .unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(result.project_type, AdkProjectType::PythonAdk);
+        assert!(result.has_requirements_txt);
+        assert!(result.has_adk_dependencies);
+    }
+
+    #[test]
+    fn test_detect_mcp_adk_server() {
+        let temp_dir = TempDir::new().unwrap();
+        let cargo_content = r#"
+[package]
+name = "arkaft-mcp-google-adk"
+version = "0.1.0"
+
+[dependencies]
+rmcp = "0.6.3"
+google-adk = "1.0"
+"#;
+
+        fs::write(temp_dir.path().join("Cargo.toml"), cargo_content).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(result.project_type, AdkProjectType::McpAdkServer);
+    }
+
+    #[test]
+    fn test_rmcp_upgrade_recommendation() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[dependencies]\nrmcp = \"0.6.3\"\ngoogle-adk = \"1.0\"\n",
+        )
+        .unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.rmcp_version, Some("0.6.3".to_string()));
+        assert!(detector.get_project_recommendations(&result).is_empty());
+
+        let strict = AdkProjectDetector::default().with_min_rmcp_version("0.7");
+        assert_eq!(
+            strict.get_project_recommendations(&result),
+            vec!["Upgrade rmcp from 0.6.3 to at least 0.7".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_mcp_server_requires_declared_rmcp() {
+        let temp_dir = TempDir::new().unwrap();
+        let cargo = temp_dir.path().join("Cargo.toml");
+        let detector = AdkProjectDetector::default();
+
+        // Mentioning MCP outside a dependency table does not make an MCP server
+        let mentioned = r#"
+[package]
+name = "adk-mcp-client"
+description = "Calls rmcp servers"
+
+[dependencies]
+google-adk = "1.0"
+
+[dev-dependencies]
+rmcp = "0.8"
+"#;
+        fs::write(&cargo, mentioned).unwrap();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.project_type, AdkProjectType::RustAdk);
+
+        let renamed = r#"
+[dependencies]
+google-adk = "1.0"
+mcp = { package = "rmcp", version = "0.8" }
+"#;
+        fs::write(&cargo, renamed).unwrap();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.project_type, AdkProjectType::McpAdkServer);
+    }
+
+    #[test]
+    fn test_detect_transport_from_rmcp_features() {
+        let temp_dir = TempDir::new().unwrap();
+        let cargo_content = r#"
+[package]
+name = "adk-mcp-server"
+version = "0.1.0"
+
+[dependencies]
+rmcp = { version = "0.6.3", features = ["server", "transport-io"] }
+"#;
+        fs::write(temp_dir.path().join("Cargo.toml"), cargo_content).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.transport, Some(Transport::Stdio));
+
+        let config_dir = TempDir::new().unwrap();
+        fs::write(
+            config_dir.path().join("mcp.json"),
+            "{\n  \"mcpServers\": {\n    \"adk\": {\n      \"type\": \"sse\",\n      \"url\": \"http://localhost:8080/sse\"\n    }\n  }\n}\n",
+        )
+        .unwrap();
+        let result = detector.detect_adk_project(config_dir.path()).unwrap();
+        assert_eq!(result.transport, Some(Transport::Sse));
+
+        // An explicit config transport wins over the rmcp features, which are read from the
+        // parsed manifest rather than its text
+        let both = r#"
+[dependencies]
+# transport-sse was dropped
+rmcp = { version = "0.8", features = ["server", "transport-io", "transport-sse-server"] }
+"#;
+        fs::write(temp_dir.path().join("Cargo.toml"), both).unwrap();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.transport, Some(Transport::Stdio));
+
+        let config = r#"{"mcpServers": {"adk": {"transport": "SSE", "url": "http://x/sse"}}}"#;
+        fs::write(temp_dir.path().join("mcp.json"), config).unwrap();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.transport, Some(Transport::Sse));
+
+        let commented = "[dependencies]\nrmcp = \"0.8\"\n# features = [\"transport-io\"]\n";
+        fs::write(temp_dir.path().join("Cargo.toml"), commented).unwrap();
+        fs::remove_file(temp_dir.path().join("mcp.json")).unwrap();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.transport, None);
+    }
+
+    #[test]
+    fn test_pin_check_flags_wildcard_adk_version() {
+        let temp_dir = TempDir::new().unwrap();
+        let cargo_content = r#"
+[package]
+name = "loose-agent"
+version = "0.1.0"
+
+[dependencies]
+google-adk = "*"
+serde = ">=1.0"
+tokio = { version = "1.40", features = ["full"] }
+"#;
+        fs::write(temp_dir.path().join("Cargo.toml"), cargo_content).unwrap();
+
+        let unchecked = AdkProjectDetector::default();
+        assert!(unchecked.detect_adk_project(temp_dir.path()).unwrap().loose_versions.is_empty());
+
+        let detector = AdkProjectDetector::default().with_pin_check(PinCheck::Adk);
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(
+            result.loose_versions,
+            vec![LooseVersion {
+                dependency: "google-adk".to_string(),
+                requirement: "*".to_string(),
+                manifest: PathBuf::from("Cargo.toml"),
+            }]
+        );
+        assert!(detector
+            .get_project_recommendations(&result)
+            .contains(&"Pin google-adk (currently \"*\") in Cargo.toml to a specific version".to_string()));
+
+        let all = AdkProjectDetector::default().with_pin_check(PinCheck::All);
+        let names: Vec<String> = all
+            .detect_adk_project(temp_dir.path())
+            .unwrap()
+            .loose_versions
+            .into_iter()
+            .map(|loose| loose.dependency)
+            .collect();
+        assert_eq!(names, vec!["google-adk".to_string(), "serde".to_string()]);
+
+        assert!(is_loose_requirement(""));
+        assert!(is_loose_requirement(">=1.0"));
+        assert!(!is_loose_requirement(">=1.0,<2"));
+        assert!(!is_loose_requirement("==1.2.0"));
+        assert!(!is_loose_requirement("^1.2"));
+    }
+
+    #[test]
+    fn test_detect_session_backend() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("main.py"),
+            "from google.adk.sessions import DatabaseSessionService\n\nsessions = DatabaseSessionService(db_url=DB_URL)\n",
+        )
+        .unwrap();
+        fs::create_dir(temp_dir.path().join("tests")).unwrap();
+        fs::write(
+            temp_dir.path().join("tests/test_agent.py"),
+            "from google.adk.sessions import InMemorySessionService\n",
+        )
+        .unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.session_backend, Some("database".to_string()));
+
+        let empty_dir = TempDir::new().unwrap();
+        let result = detector.detect_adk_project(empty_dir.path()).unwrap();
+        assert_eq!(result.session_backend, None);
+    }
+
+    #[test]
+    fn test_primary_manifest() {
+        let detector = AdkProjectDetector::default();
+
+        let rust_dir = TempDir::new().unwrap();
+        fs::write(rust_dir.path().join("Cargo.toml"), "[dependencies]\ngoogle-adk = \"1.0\"\n").unwrap();
+        let rust = detector.detect_adk_project(rust_dir.path()).unwrap();
+        assert_eq!(rust.project_type, AdkProjectType::RustAdk);
+        assert_eq!(rust.primary_manifest(), Some(rust_dir.path().join("Cargo.toml")));
+
+        let python_dir = TempDir::new().unwrap();
+        fs::write(python_dir.path().join("requirements.txt"), "google-adk==1.0.0\n").unwrap();
+        let python = detector.detect_adk_project(python_dir.path()).unwrap();
+        assert_eq!(python.project_type, AdkProjectType::PythonAdk);
+        assert_eq!(python.primary_manifest(), Some(python_dir.path().join("requirements.txt")));
+
+        fs::write(python_dir.path().join("pyproject.toml"), "[project]\nname = \"agent\"\n").unwrap();
+        let python = detector.detect_adk_project(python_dir.path()).unwrap();
+        assert_eq!(python.primary_manifest(), Some(python_dir.path().join("pyproject.toml")));
+
+        let mixed_dir = TempDir::new().unwrap();
+        fs::write(mixed_dir.path().join("Cargo.toml"), "[dependencies]\ngoogle-adk = \"1.0\"\n").unwrap();
+        fs::write(mixed_dir.path().join("requirements.txt"), "google-adk==1.0.0\n").unwrap();
+        let mixed = detector.detect_adk_project(mixed_dir.path()).unwrap();
+        assert_eq!(mixed.project_type, AdkProjectType::Mixed);
+        assert_eq!(mixed.primary_manifest(), Some(mixed_dir.path().join("Cargo.toml")));
+
+        // In a Mixed project the manifest declaring ADK wins over a plain Cargo.toml
+        fs::write(mixed_dir.path().join("Cargo.toml"), "[package]\nname = \"bindings\"\n").unwrap();
+        let mixed = detector.detect_adk_project(mixed_dir.path()).unwrap();
+        assert_eq!(mixed.project_type, AdkProjectType::Mixed);
+        assert_eq!(mixed.primary_manifest(), Some(mixed_dir.path().join("requirements.txt")));
+
+        // package.json is listed but only used when no other manifest exists
+        let config_dir = TempDir::new().unwrap();
+        fs::write(config_dir.path().join("package.json"), "{\"name\": \"agent-ui\"}").unwrap();
+        fs::write(config_dir.path().join(".env"), "GOOGLE_API_KEY=abc\n").unwrap();
+        let config_only = detector.detect_adk_project(config_dir.path()).unwrap();
+        assert_eq!(config_only.manifest_paths, vec![PathBuf::from("package.json")]);
+        assert_eq!(config_only.primary_manifest(), Some(config_dir.path().join("package.json")));
+    }
+
+    #[test]
+    fn test_license_scan_flags_gpl_dependency() {
+        let temp_dir = TempDir::new().unwrap();
+        for (name, license) in [("serde", "MIT OR Apache-2.0"), ("adk-gpl-tools", "GPL-3.0-only")] {
+            let crate_dir = temp_dir.path().join("vendor").join(name);
+            fs::create_dir_all(&crate_dir).unwrap();
+            fs::write(
+                crate_dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{}\"\nversion = \"1.0.0\"\nlicense = \"{}\"\n", name, license),
+            )
+            .unwrap();
+        }
+
+        let unscanned = AdkProjectDetector::default();
+        assert!(unscanned.detect_adk_project(temp_dir.path()).unwrap().license_warnings.is_empty());
+
+        let detector = AdkProjectDetector::default().with_license_scan(true);
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(
+            result.license_warnings,
+            vec![format!(
+                "adk-gpl-tools is licensed under GPL-3.0-only ({})",
+                Path::new("vendor/adk-gpl-tools/Cargo.toml").display()
+            )]
+        );
+
+        assert!(is_permissive_license("Apache-2.0 WITH LLVM-exception"));
+        assert!(is_permissive_license("MIT/Apache-2.0"));
+        assert!(!is_permissive_license("MIT AND GPL-2.0"));
+        assert!(!is_permissive_license("AGPL-3.0-or-later"));
+    }
+
+    #[test]
+    fn test_installed_adk_version() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("requirements.txt"), "google-adk>=1.0\n").unwrap();
+        let site_packages = temp_dir.path().join(".venv/lib/python3.12/site-packages");
+        for dist_info in ["google_adk-1.4.2.dist-info", "google_adk_extras-0.3.0.dist-info"] {
+            fs::create_dir_all(site_packages.join(dist_info)).unwrap();
+        }
+
+        let unscanned = AdkProjectDetector::default();
+        assert_eq!(unscanned.detect_adk_project(temp_dir.path()).unwrap().installed_adk_version, None);
+
+        let detector = AdkProjectDetector::default().with_installed_version_scan(true);
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.installed_adk_version.as_deref(), Some("1.4.2"));
+    }
+
+    #[test]
+    fn test_external_integrations() {
+        let temp_dir = TempDir::new().unwrap();
+        let cargo_content = r#"
+[package]
+name = "integrated-agent"
+version = "0.1.0"
+
+[dependencies]
+google-adk = "1.0"
+sqlx = { version = "0.8", features = ["postgres"] }
+reqwest = "0.12"
+serde = "1"
+"#;
+        fs::write(temp_dir.path().join("Cargo.toml"), cargo_content).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(result.external_integrations, vec!["reqwest".to_string(), "sqlx".to_string()]);
+
+        // PEP 621 and Poetry dependencies in pyproject.toml count as well
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            "[project]\nname = \"agent\"\ndependencies = [\"google-adk>=1.0\", \"httpx>=0.27\"]\n\n[tool.poetry.dependencies]\npython = \"^3.11\"\nasyncpg = \"^0.29\"\n",
+        )
+        .unwrap();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.external_integrations, vec!["asyncpg", "httpx", "reqwest", "sqlx"]);
+    }
+
+    #[test]
+    fn test_detect_guardrails() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("agent.py"),
+            "from google.adk.agents import Agent\n\nroot_agent = Agent(name=\"helper\")\n",
+        )
+        .unwrap();
+
+        let detector = AdkProjectDetector::default();
+        assert!(!detector.detect_adk_project(temp_dir.path()).unwrap().has_guardrails);
+
+        fs::write(
+            temp_dir.path().join("agent.py"),
+            r#"from google.adk.agents import Agent
+
+def block_keyword(callback_context, llm_request):
+    return None
+
+root_agent = Agent(
+    name="helper",
+    before_model_callback=block_keyword,
+)
+"#,
+        )
+        .unwrap();
+        assert!(detector.detect_adk_project(temp_dir.path()).unwrap().has_guardrails);
+    }
+
+    #[test]
+    fn test_detect_health_check() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("server.py"),
+            "from fastapi import FastAPI\n\napp = FastAPI()\n\n@app.get(\"/run\")\ndef run():\n    return {}\n\nHEALTH_DOC = \"/health\"\n",
+        )
+        .unwrap();
+
+        let detector = AdkProjectDetector::default();
+        assert!(!detector.detect_adk_project(temp_dir.path()).unwrap().has_health_check);
+
+        fs::write(
+            temp_dir.path().join("server.py"),
+            r#"from fastapi import FastAPI
+
+app = FastAPI()
+
+@app.get("/health")
+def health():
+    return {"status": "ok"}
+"#,
+        )
+        .unwrap();
+        assert!(detector.detect_adk_project(temp_dir.path()).unwrap().has_health_check);
+    }
+
+    #[test]
+    fn test_detect_cargo_targets() {
+        let temp_dir = TempDir::new().unwrap();
+        let cargo_content = r#"
+[package]
+name = "adk-tools"
+version = "0.1.0"
+
+[[bin]]
+name = "adk-server"
+path = "src/server.rs"
+
+[dependencies]
+google-adk = "1.0"
+"#;
+        fs::write(temp_dir.path().join("Cargo.toml"), cargo_content).unwrap();
+        fs::create_dir_all(temp_dir.path().join("src/bin")).unwrap();
+        fs::write(temp_dir.path().join("src/server.rs"), "fn main() {}\n").unwrap();
+        fs::write(temp_dir.path().join("src/bin/adk-cli.rs"), "fn main() {}\n").unwrap();
+        fs::write(temp_dir.path().join("src/lib.rs"), "").unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(
+            result.cargo_targets,
+            vec![
+                CargoTarget {
+                    name: "adk-server".to_string(),
+                    kind: CargoTargetKind::Bin,
+                    path: PathBuf::from("src/server.rs"),
+                },
+                CargoTarget {
+                    name: "adk_tools".to_string(),
+                    kind: CargoTargetKind::Lib,
+                    path: PathBuf::from("src/lib.rs"),
+                },
+                CargoTarget {
+                    name: "adk-cli".to_string(),
+                    kind: CargoTargetKind::Bin,
+                    path: PathBuf::from("src/bin/adk-cli.rs"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_rmcp_version_forms() {
+        let rmcp = |content: &str| matching::extract_dependency_version(content, &["rmcp"]);
+        let inline = "[dependencies]\nrmcp = { version = \"0.5.1\", features = [\"server\"] }\n";
+        assert_eq!(rmcp(inline), Some("0.5.1".to_string()));
+
+        let table = "[dependencies.rmcp]\nfeatures = [\"server\"]\nversion = \"=0.6.3\"\n";
+        assert_eq!(rmcp(table), Some("=0.6.3".to_string()));
+
+        let target = "[target.'cfg(unix)'.dependencies]\nrmcp = { version = \"0.7\" }\n";
+        assert_eq!(rmcp(target), Some("0.7".to_string()));
+        let renamed = "[dependencies]\nmcp = { package = \"rmcp\", version = \"0.8\" }\n";
+        assert_eq!(rmcp(renamed), Some("0.8".to_string()));
+
+        assert_eq!(rmcp("[dependencies]\nrmcp-macros = \"1\"\n"), None);
+
+        assert!(version_below("^0.5.1", "0.6.0"));
+        assert!(!version_below("0.6", "0.6.0"));
+        assert!(!version_below("1.0.0-beta", "0.6.0"));
+        // A range resolves to its lower bound, not a version built from all its numbers
+        assert!(version_below(">=0.5, <0.8", "0.5.8"));
+        assert!(!version_below(">=0.6, <0.8", "0.6"));
+        assert!(!version_below("*", "0.6.0"));
+    }
+
+    #[test]
+    fn test_detect_non_adk_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let cargo_content = r#"
+[package]
+name = "regular-rust"
+version = "0.1.0"
+
+[dependencies]
+serde = "1.0"
+tokio = "1.0"
+"#;
+
+        fs::write(temp_dir.path().join("Cargo.toml"), cargo_content).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(result.project_type, AdkProjectType::None);
+        assert!(result.has_cargo_toml);
+        assert!(!result.has_adk_dependencies);
+    }
+
+    #[test]
+    fn test_detect_a2a_agent_card() {
+        let temp_dir = TempDir::new().unwrap();
+        let well_known = temp_dir.path().join(".well-known");
+        fs::create_dir_all(&well_known).unwrap();
+        let card_content = r#"
+{
+  "name": "weather_agent",
+  "version": "1.0.0",
+  "url": "https://agents.example.com/weather",
+  "capabilities": { "streaming": true, "pushNotifications": false },
+  "skills": [
+    { "id": "get_forecast", "name": "Get forecast" },
+    { "name": "current_conditions" }
+  ]
+}
+"#;
+        fs::write(well_known.join("agent.json"), card_content).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        let a2a = result.a2a.expect("agent card should be detected");
+        assert_eq!(a2a.name, Some("weather_agent".to_string()));
+        assert_eq!(a2a.skills, vec!["get_forecast", "current_conditions"]);
+        assert_eq!(a2a.capabilities, vec!["streaming"]);
+    }
+
+    #[test]
+    fn test_detection_error_variants() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("requirements.txt"), "google-adk==1.0.0\n").unwrap();
+        let card_path = temp_dir.path().join("agent.json");
+        fs::write(&card_path, r#"{"name": "weather_agent", "skills": ["#).unwrap();
+
+        // A malformed card is ignored unless parsing is strict
+        let lenient = AdkProjectDetector::default().detect_adk_project(temp_dir.path()).unwrap();
+        assert!(lenient.a2a.is_none());
+        let strict = AdkProjectDetector::default().with_strict_parsing(true);
+        let err = strict.detect_adk_project(temp_dir.path()).unwrap_err();
+        assert!(matches!(err, DetectionError::ParseError { ref path, .. } if *path == card_path));
+
+        fs::remove_file(&card_path).unwrap();
+        fs::create_dir(&card_path).unwrap();
+        let err = strict.detect_adk_project(temp_dir.path()).unwrap_err();
+        assert!(matches!(err, DetectionError::UnreadableConfig { ref path, .. } if *path == card_path));
+
+        // A project path that is not a directory cannot be listed
+        let err = strict.detect_adk_project(temp_dir.path().join("requirements.txt")).unwrap_err();
+        assert!(matches!(err, DetectionError::Io(_)));
+    }
+
+    #[test]
+    fn test_find_adk_projects_sorted() {
+        let temp_dir = TempDir::new().unwrap();
+        for name in ["zeta", "alpha", "mid"] {
+            let project_dir = temp_dir.path().join(name);
+            fs::create_dir_all(&project_dir).unwrap();
+            fs::write(project_dir.join("requirements.txt"), "google-adk==1.0.0").unwrap();
+        }
+
+        let detector = AdkProjectDetector::default();
+        let first: Vec<PathBuf> = detector
+            .find_adk_projects(temp_dir.path())
+            .unwrap()
+            .into_iter()
+            .map(|project| project.root_path)
+            .collect();
+        let second: Vec<PathBuf> = detector
+            .find_adk_projects(temp_dir.path())
+            .unwrap()
+            .into_iter()
+            .map(|project| project.root_path)
+            .collect();
+
+        assert_eq!(first, second);
+        assert_eq!(
+            first,
+            vec![
+                temp_dir.path().join("alpha"),
+                temp_dir.path().join("mid"),
+                temp_dir.path().join("zeta"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_adk_projects_in_workspace() {
+        let temp_dir = TempDir::new().unwrap();
+        // Glob metacharacters in the checkout path must not break member matching
+        let root = temp_dir.path().join("checkout [v2]*");
+        fs::create_dir(&root).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.dependencies]\ngoogle-adk = \"1.0\"\n",
+        )
+        .unwrap();
+        for (name, dependency) in [
+            ("planner", "google-adk.workspace = true"),
+            ("executor", "google-adk = \"1.0\""),
+            ("utils", "serde = \"1\""),
+        ] {
+            let crate_dir = root.join("crates").join(name);
+            fs::create_dir_all(crate_dir.join("src")).unwrap();
+            fs::write(crate_dir.join("src/lib.rs"), "").unwrap();
+            fs::write(
+                crate_dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{}\"\nversion = \"0.1.0\"\n\n[dependencies]\n{}\n", name, dependency),
+            )
+            .unwrap();
+        }
+
+        let detector = AdkProjectDetector::default();
+        let projects = detector.find_adk_projects(&root).unwrap();
+
+        // The virtual root is not reported, only the two ADK members
+        let roots: Vec<PathBuf> = projects.iter().map(|p| p.root_path.clone()).collect();
+        assert_eq!(roots, vec![root.join("crates/executor"), root.join("crates/planner")]);
+        assert!(projects.iter().all(|p| p.project_type == AdkProjectType::RustAdk));
+    }
+
+    #[test]
+    fn test_find_adk_projects_in_submodule() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join(".gitmodules"),
+            "[submodule \"third_party/adk-samples\"]\n\tpath = third_party/adk-samples\n\turl = https://github.com/google/adk-samples.git\n",
+        )
+        .unwrap();
+        let app_dir = temp_dir.path().join("app");
+        let vendored_dir = temp_dir.path().join("third_party/adk-samples");
+        for dir in [&app_dir, &vendored_dir] {
+            fs::create_dir_all(dir).unwrap();
+            fs::write(dir.join("requirements.txt"), "google-adk==1.0.0\n").unwrap();
+        }
+
+        let detector = AdkProjectDetector::default();
+        let projects = detector.find_adk_projects(temp_dir.path()).unwrap();
+        assert_eq!(projects.len(), 2);
+        assert_eq!(projects[0].root_path, app_dir);
+        assert!(!projects[0].in_submodule);
+        assert_eq!(projects[1].root_path, vendored_dir);
+        assert!(projects[1].in_submodule);
+        assert_eq!(projects[1].submodule_path, Some(PathBuf::from("third_party/adk-samples")));
+
+        let projects = detector.with_skip_submodules(true).find_adk_projects(temp_dir.path()).unwrap();
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].root_path, app_dir);
+    }
+
+    #[test]
+    fn test_find_adk_projects_with_config_override() {
+        let temp_dir = TempDir::new().unwrap();
+        let nested = temp_dir.path().join("services/agents/weather");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(nested.join("requirements.txt"), "google-adk==1.0.0").unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let deep = crate::DetectionConfig {
+            max_depth: 5,
+            ..Default::default()
+        };
+        let shallow = crate::DetectionConfig {
+            max_depth: 1,
+            ..Default::default()
+        };
+
+        let found = detector.find_adk_projects_with(temp_dir.path(), &deep).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].root_path, nested);
+        assert!(detector.find_adk_projects_with(temp_dir.path(), &shallow).unwrap().is_empty());
+
+        // The detector's own depth is untouched by either call
+        assert!(detector.find_adk_projects(temp_dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_project_info_display() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[dependencies]\ngoogle-adk = { version = \"1.2.0\" }\n",
+        )
+        .unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let summary = detector.detect_adk_project(temp_dir.path()).unwrap().to_string();
+
+        assert!(summary.contains("ADK project: RustAdk"));
+        assert!(summary.contains("ADK version: 1.2.0"));
+        assert!(summary.contains("ADK dependencies: yes"));
+    }
+
+    #[test]
+    fn test_syntax_probe_broken_entrypoint() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[dependencies]\ngoogle-adk = \"1.0\"\n",
+        )
+        .unwrap();
+        fs::create_dir(temp_dir.path().join("src")).unwrap();
+        fs::write(
+            temp_dir.path().join("src/main.rs"),
+            "fn main() {\n    let s = r#\"}\"#;\n    println!(\"{}\", s);\n",
+        )
+        .unwrap();
+
+        let detector = AdkProjectDetector::default().with_syntax_probe(true);
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(result.manifest_parses, Some(true));
+        assert_eq!(result.entrypoint_parses, Some(false));
+
+        // The probe is opt-in
+        let result = AdkProjectDetector::default()
+            .detect_adk_project(temp_dir.path())
+            .unwrap();
+        assert_eq!(result.entrypoint_parses, None);
+    }
+
+    #[test]
+    fn test_tokenizes_cleanly() {
+        let rust = "fn f<'a>(x: &'a str) -> char {\n    // ) unbalanced in comment\n    /* { /* nested */ */\n    let _ = \"(\";\n    ']'\n}\n";
+        assert!(tokenizes_cleanly(rust, SourceLanguage::Rust));
+        assert!(!tokenizes_cleanly("fn f() { let s = \"open; }", SourceLanguage::Rust));
+
+        let python = "def f(x):\n    \"\"\"Doc with ( paren\"\"\"\n    return [x, ')']  # ]\n";
+        assert!(tokenizes_cleanly(python, SourceLanguage::Python));
+        assert!(!tokenizes_cleanly("print('unterminated)\n", SourceLanguage::Python));
+        assert!(!tokenizes_cleanly("items = [1, 2\n", SourceLanguage::Python));
+    }
+
+    #[test]
+    fn test_python_packages_src_layout() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("requirements.txt"), "google-adk==1.0.0\n").unwrap();
+        fs::create_dir_all(root.join("src/my_agent")).unwrap();
+        fs::write(root.join("src/my_agent/__init__.py"), "").unwrap();
+        // PEP 420 namespace package: no __init__.py, only a subpackage
+        fs::create_dir_all(root.join("src/acme/tools")).unwrap();
+        fs::write(root.join("src/acme/tools/__init__.py"), "").unwrap();
+        fs::create_dir_all(root.join("tests")).unwrap();
+        fs::write(root.join("tests/__init__.py"), "").unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(root).unwrap();
+
+        assert_eq!(
+            result.python_packages,
+            vec![PathBuf::from("src/acme"), PathBuf::from("src/my_agent")]
+        );
+    }
+
+    #[test]
+    fn test_python_packages_flat_layout() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("requirements.txt"), "google-adk==1.0.0\n").unwrap();
+        fs::create_dir_all(root.join("multi_tool_agent")).unwrap();
+        fs::write(root.join("multi_tool_agent/__init__.py"), "").unwrap();
+        fs::write(root.join("multi_tool_agent/agent.py"), "").unwrap();
+        // Plain directories of scripts are not packages in the flat layout
+        fs::create_dir_all(root.join("tools")).unwrap();
+        fs::write(root.join("tools/helper.py"), "").unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(root).unwrap();
+
+        assert_eq!(result.python_packages, vec![PathBuf::from("multi_tool_agent")]);
+    }
+
+    #[test]
+    fn test_project_diff() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[dependencies]\ngoogle-adk = { version = \"1.0.0\" }\n",
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("README.md"), "x".repeat(10_000)).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let before = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[dependencies]\ngoogle-adk = { version = \"1.1.0\" }\n",
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("README.md"), "x".repeat(10_100)).unwrap();
+        let after = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_ne!(before.estimated_size, after.estimated_size);
+        let diff = before.diff(&after);
+        assert_eq!(diff.changes.len(), 1);
+        let change = diff.change("adk_version").unwrap();
+        assert_eq!(change.before, "1.0.0");
+        assert_eq!(change.after, "1.1.0");
+        assert!(diff.change("estimated_size").is_none());
+
+        assert!(after.diff(&after).is_empty());
+    }
+
+    #[test]
+    fn test_project_diff_dependencies_and_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let cargo = temp_dir.path().join("Cargo.toml");
+        fs::write(&cargo, "[dependencies]\ngoogle-adk = \"1.0\"\n").unwrap();
+        fs::write(temp_dir.path().join("adk.toml"), "[ADK]\n").unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let before = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        fs::write(&cargo, "[dependencies]\ngoogle-adk = \"1.0\"\nrmcp = \"0.8\"\n").unwrap();
+        fs::write(temp_dir.path().join(".env"), "GOOGLE_API_KEY=x\n").unwrap();
+        let added = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        let diff = before.diff(&added);
+        assert!(diff.change("has_adk_dependencies").is_none());
+        assert!(diff.change("has_adk_config").is_none());
+        let dependencies = diff.change("adk_dependencies").unwrap();
+        assert_eq!(dependencies.before, "google-adk");
+        assert_eq!(dependencies.after, "google-adk, rmcp");
+        assert_eq!(diff.change("rmcp_version").unwrap().after, "0.8");
+        let config = diff.change("adk_config").unwrap();
+        assert_eq!(config.before, "adk.toml: ADK");
+        assert_eq!(config.after, ".env: GOOGLE_API_KEY, adk.toml: ADK");
+
+        fs::write(&cargo, "[dependencies]\ngoogle-adk = \"1.0\"\n").unwrap();
+        let removed = detector.detect_adk_project(temp_dir.path()).unwrap();
+        let dependencies = added.diff(&removed).change("adk_dependencies").cloned().unwrap();
+        assert_eq!(dependencies.before, "google-adk, rmcp");
+        assert_eq!(dependencies.after, "google-adk");
+
+        fs::write(temp_dir.path().join("README.md"), "x".repeat(200 * 1024)).unwrap();
+        let grown = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert!(removed.diff(&grown).change("estimated_size").is_some());
+    }
+
+    #[test]
+    fn test_python_entry_points() {
+        let temp_dir = TempDir::new().unwrap();
+        let pyproject_content = r#"
+[project]
+name = "my-agent"
+dependencies = ["google-adk>=1.0.0"]
+
+[project.scripts]
+run-agent = "pkg.main:main"
+"#;
+        fs::write(temp_dir.path().join("pyproject.toml"), pyproject_content).unwrap();
+        let setup_cfg_content = "[metadata]\nname = my-agent\n\n[options.entry_points]\nconsole_scripts =\n    agent-cli = pkg.cli:run\n";
+        fs::write(temp_dir.path().join("setup.cfg"), setup_cfg_content).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(result.entry_points.len(), 2);
+        assert_eq!(
+            result.entry_points.get("run-agent"),
+            Some(&"pkg.main:main".to_string())
+        );
+        assert_eq!(
+            result.entry_points.get("agent-cli"),
+            Some(&"pkg.cli:run".to_string())
+        );
+    }
+
+    #[test]
+    fn test_description_from_manifest() {
+        let temp_dir = TempDir::new().unwrap();
+        let cargo_content = r#"
+[package]
+name = "weather-agent"
+description = "Answers weather questions with Gemini"
+
+[dependencies]
+google-adk = "1.0"
+"#;
+        fs::write(temp_dir.path().join("Cargo.toml"), cargo_content).unwrap();
+        fs::write(temp_dir.path().join("README.md"), "# Weather\n\nREADME text.\n").unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(
+            result.description,
+            Some("Answers weather questions with Gemini".to_string())
+        );
+    }
+
+    #[test]
+    fn test_description_from_readme() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("requirements.txt"), "google-adk==1.0.0\n").unwrap();
+        let readme_content = "# Support Agent\n\n[![CI](https://example.com/badge.svg)](https://example.com)\n\nA multi-tool ADK agent that\ntriages support tickets.\n\n## Setup\n";
+        fs::write(temp_dir.path().join("README.md"), readme_content).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(
+            result.description,
+            Some("A multi-tool ADK agent that triages support tickets.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_task_targets_justfile() {
+        let temp_dir = TempDir::new().unwrap();
+        let justfile_content = r#"
+set dotenv-load := true
+region := "us-central1"
+
+# Deploy the agent to Cloud Run
+deploy-agent: test
+    adk deploy cloud_run --region {{region}} ./multi_tool_agent
+
+test:
+    cargo test
+
+web port="8000":
+    adk web --port {{port}}
+"#;
+        fs::write(temp_dir.path().join("justfile"), justfile_content).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        let targets: Vec<(&str, bool)> = result
+            .task_targets
+            .iter()
+            .map(|t| (t.name.as_str(), t.looks_adk))
+            .collect();
+        assert_eq!(targets, vec![("deploy-agent", true), ("test", false), ("web", true)]);
+        assert_eq!(result.task_targets[0].source, PathBuf::from("justfile"));
+    }
+
+    #[test]
+    fn test_task_targets_makefile_and_taskfile() {
+        let temp_dir = TempDir::new().unwrap();
+        let makefile_content = ".PHONY: lint eval\nPYTHON ?= python3\n\nlint:\n\truff check .\n\neval: lint\n\t$(PYTHON) -m evals\n";
+        fs::write(temp_dir.path().join("Makefile"), makefile_content).unwrap();
+        let taskfile_content = "version: '3'\n\ntasks:\n  build:\n    cmds:\n      - cargo build\n  serve-agent:\n    cmds:\n      - cargo run\n";
+        fs::write(temp_dir.path().join("Taskfile.yml"), taskfile_content).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        let targets: Vec<(&str, bool)> = result
+            .task_targets
+            .iter()
+            .map(|t| (t.name.as_str(), t.looks_adk))
+            .collect();
+        assert_eq!(
+            targets,
+            vec![("lint", false), ("eval", true), ("build", false), ("serve-agent", true)]
+        );
+    }
+
+    #[test]
+    fn test_configurable_skip_dirs() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("requirements.txt"), "google-adk==1.0.0\n").unwrap();
+        fs::create_dir(temp_dir.path().join(".tox")).unwrap();
+        fs::write(temp_dir.path().join(".tox/cache.bin"), vec![0u8; 4096]).unwrap();
+
+        let default_size = AdkProjectDetector::default()
+            .detect_adk_project(temp_dir.path())
+            .unwrap()
+            .estimated_size;
+
+        let mut config = crate::DetectionConfig::default();
+        config.skip_dirs.push(".tox".to_string());
+        let detector = AdkProjectDetector::from_config(&config);
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(result.estimated_size, default_size - 4096);
+        assert_eq!(result.estimated_size, "google-adk==1.0.0\n".len() as u64);
+    }
+
+    #[test]
+    fn test_detect_vendored_sources() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"agent\"\n\n[dependencies]\ngoogle-adk = \"1.0\"\n",
+        )
+        .unwrap();
+        fs::create_dir(temp_dir.path().join(".cargo")).unwrap();
+        let cargo_config = r#"
+[source.crates-io]
+replace-with = "vendored-sources"
+
+[source.vendored-sources]
+directory = "third_party"
+"#;
+        fs::write(temp_dir.path().join(".cargo/config.toml"), cargo_config).unwrap();
+        fs::create_dir_all(temp_dir.path().join("third_party/google-adk")).unwrap();
+        fs::write(temp_dir.path().join("third_party/google-adk/lib.rs"), vec![b'/'; 4096]).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert!(result.vendored);
+        assert_eq!(result.vendor_dirs, vec![PathBuf::from("third_party")]);
+        // Vendored sources do not count towards the project size
+        let manifest_size = fs::metadata(temp_dir.path().join("Cargo.toml")).unwrap().len();
+        let config_size = cargo_config.len() as u64;
+        assert_eq!(result.estimated_size, manifest_size + config_size);
+    }
+
+    #[test]
+    fn test_supported_platforms_from_classifiers() {
+        let temp_dir = TempDir::new().unwrap();
+        let pyproject = r#"
+[project]
+name = "weather-agent"
+dependencies = ["google-adk>=1.0"]
+classifiers = [
+    "Programming Language :: Python :: 3",
+    "Programming Language :: Python :: 3.11",
+    "Programming Language :: Python :: 3.12",
+    "Programming Language :: Python :: 3 :: Only",
+    "Operating System :: POSIX :: Linux",
+    "Operating System :: MacOS",
+    "License :: OSI Approved :: Apache Software License",
+]
+"#;
+        fs::write(temp_dir.path().join("pyproject.toml"), pyproject).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(
+            result.supported_platforms,
+            vec!["Python 3", "Python 3.11", "Python 3.12", "Linux", "MacOS"]
+        );
+    }
+
+    #[test]
+    fn test_tool_requirements_from_imported_clients() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("requirements.txt"), "google-adk==1.2.0\nweather-api==2.0\n").unwrap();
+        let agent = r#"
+import os
+from google.adk.agents import Agent
+from google.adk.tools import FunctionTool
+from weather_api import WeatherClient
+
+
+def get_weather(city: str) -> dict:
+    """Current weather for a city."""
+    return WeatherClient().current(city)
+
+
+def get_time(city: str) -> dict:
+    return {"city": city}
+
+
+def search_tickets(query: str) -> list:
+    token = os.environ.get("TICKETS_TOKEN")
+    return []
+
+
+root_agent = Agent(
+    name="helper",
+    tools=[get_weather, get_time, FunctionTool(func=search_tickets)],
+)
+"#;
+        fs::write(temp_dir.path().join("agent.py"), agent).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(
+            result.tool_requirements,
+            vec![
+                ("get_weather".to_string(), vec!["WEATHER_API_KEY".to_string()]),
+                ("search_tickets".to_string(), vec!["TICKETS_TOKEN".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dev_only_adk_dependency() {
+        let temp_dir = TempDir::new().unwrap();
+        let cargo_content = r#"
+[package]
+name = "billing-service"
+version = "0.1.0"
+
+[dependencies]
+tokio = "1.0"
+
+[dev-dependencies]
+google-adk = "1.0"
+"#;
+        fs::write(temp_dir.path().join("Cargo.toml"), cargo_content).unwrap();
+
+        let result = AdkProjectDetector::default().detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.project_type, AdkProjectType::RustAdk);
+
+        let detector = AdkProjectDetector::default().with_consider_dev_deps(false);
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.project_type, AdkProjectType::None);
+        assert!(!result.has_adk_dependencies);
+    }
+
+    #[test]
+    fn test_signal_attribution() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"agent\"\n\n[dependencies]\ngoogle-adk = \"1.0\"\ntokio = \"1\"\n",
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join(".env"), "GOOGLE_API_KEY=abc123\n").unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(
+            result.signal_attribution,
+            vec![
+                SignalAttribution {
+                    signal: "dependency".to_string(),
+                    file: PathBuf::from("Cargo.toml"),
+                    matched: "google-adk".to_string(),
+                },
+                SignalAttribution {
+                    signal: "config".to_string(),
+                    file: PathBuf::from(".env"),
+                    matched: "GOOGLE_API_KEY".to_string(),
+                },
+            ]
+        );
+    }
 
-        if !file_path.exists() {
-            return Ok(false);
-        }
+    #[test]
+    fn test_project_marker_overrides_heuristics() {
+        let temp_dir = TempDir::new().unwrap();
+        // Heuristics alone would call this a plain Rust crate
+        fs::write(temp_dir.path().join("Cargo.toml"), "[package]\nname = \"bridge\"\n").unwrap();
+        fs::write(
+            temp_dir.path().join(".adk-project"),
+            "type = \"python-adk\"\nversion = \"1.2.0\"\nowner = \"agents-team\"\n",
+        )
+        .unwrap();
 
-        let metadata = fs::metadata(file_path)
-            .with_context(|| format!("Failed to get metadata for {:?}", file_path))?;
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
 
-        // Check file size
-        if metadata.len() > self.max_file_size {
-            return Ok(false);
-        }
+        assert_eq!(result.project_type, AdkProjectType::PythonAdk);
+        assert_eq!(result.none_reason, None);
+        assert_eq!(result.adk_version.as_deref(), Some("1.2.0"));
+        assert_eq!(
+            result.marker,
+            Some(AdkProjectMarker {
+                path: PathBuf::from(".adk-project"),
+                project_type: Some(AdkProjectType::PythonAdk),
+                version: Some("1.2.0".to_string()),
+                owner: Some("agents-team".to_string()),
+            })
+        );
 
-        // Check file extension for relevant types
-        if let Some(extension) = file_path.extension().and_then(|ext| ext.to_str()) {
-            match extension {
-                "rs" | "py" | "toml" | "json" | "yaml" | "yml" | "md" => Ok(true),
-                _ => Ok(false),
-            }
-        } else {
-            // Files without extensions - check specific names
-            if let Some(filename) = file_path.file_name().and_then(|name| name.to_str()) {
-                match filename {
-                    "Cargo.toml" | "requirements.txt" | "setup.py" | ".env" | ".env.template" => {
-                        Ok(true)
-                    }
-                    _ => Ok(false),
-                }
-            } else {
-                Ok(false)
-            }
+        // A bare YAML marker still makes the directory an ADK project
+        fs::remove_file(temp_dir.path().join(".adk-project")).unwrap();
+        fs::write(temp_dir.path().join(".adk.yaml"), "owner: agents-team\n").unwrap();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.project_type, AdkProjectType::RustAdk);
+        assert_eq!(result.marker.unwrap().owner.as_deref(), Some("agents-team"));
+    }
+
+    #[test]
+    fn test_detect_gcs_artifact_backend() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("requirements.txt"), "google-adk==1.2.0\n").unwrap();
+        let agent = r#"
+from google.adk.artifacts import GcsArtifactService, InMemoryArtifactService
+from google.adk.runners import Runner
+
+artifact_service = GcsArtifactService(bucket_name="agent-artifacts")
+"#;
+        fs::write(temp_dir.path().join("agent.py"), agent).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.artifact_backend.as_deref(), Some("gcs"));
+
+        // Bucket configuration alone also points at GCS
+        fs::remove_file(temp_dir.path().join("agent.py")).unwrap();
+        fs::write(temp_dir.path().join("adk-config.json"), r#"{"artifact_bucket": "agent-artifacts"}"#).unwrap();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.artifact_backend.as_deref(), Some("gcs"));
+    }
+
+    #[test]
+    fn test_publish_blockers_for_crate() {
+        let temp_dir = TempDir::new().unwrap();
+        let cargo_content = r#"
+[package]
+name = "weather-agent"
+version = "0.1.0"
+repository = "https://example.com/weather-agent"
+
+[dependencies]
+google-adk = "1.0"
+"#;
+        fs::write(temp_dir.path().join("Cargo.toml"), cargo_content).unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert!(!result.publishable);
+        assert_eq!(
+            result.publish_blockers,
+            vec![
+                "Cargo.toml [package] is missing `description`",
+                "Cargo.toml [package] is missing `license`",
+            ]
+        );
+
+        let complete = cargo_content.replace(
+            "version = \"0.1.0\"",
+            "version = \"0.1.0\"\ndescription = \"Weather agent\"\nlicense = \"MIT\"",
+        );
+        fs::write(temp_dir.path().join("Cargo.toml"), complete).unwrap();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert!(result.publishable);
+        assert!(result.publish_blockers.is_empty());
+    }
+
+    #[test]
+    fn test_publish_blockers_for_python() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("requirements.txt"), "google-adk==1.0.0\n").unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert!(!result.publishable);
+        assert!(result.publish_blockers[0].contains("No pyproject.toml"));
+
+        let pyproject_content = "[build-system]\nrequires = [\"hatchling\"]\nbuild-backend = \"hatchling.build\"\n\n[project]\nname = \"my-agent\"\nversion = \"0.1.0\"\n";
+        fs::write(temp_dir.path().join("pyproject.toml"), pyproject_content).unwrap();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert!(result.publishable);
+    }
+
+    #[test]
+    fn test_quick_probe_no_markers() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("README.md"), "# Not a project\n").unwrap();
+
+        let detector = AdkProjectDetector::default();
+        assert_eq!(detector.quick_probe(temp_dir.path()), QuickProbe::No);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_quick_probe_reads_no_contents() {
+        use std::process::Command;
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        let temp_dir = TempDir::new().unwrap();
+        // Opening a FIFO for reading blocks until a writer appears, so any content read hangs
+        for marker in ["Cargo.toml", ".env"] {
+            let status = Command::new("mkfifo")
+                .arg(temp_dir.path().join(marker))
+                .status()
+                .unwrap();
+            assert!(status.success());
         }
+
+        let root = temp_dir.path().to_path_buf();
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            sender.send(AdkProjectDetector::default().quick_probe(&root)).unwrap();
+        });
+
+        let probe = receiver
+            .recv_timeout(Duration::from_secs(5))
+            .expect("quick_probe blocked reading file contents");
+        assert_eq!(
+            probe,
+            QuickProbe::Maybe(vec![PathBuf::from("Cargo.toml"), PathBuf::from(".env")])
+        );
+        assert!(probe.is_maybe());
     }
 
-    /// Get a list of ADK projects in a directory tree
-    pub fn find_adk_projects<P: AsRef<Path>>(&self, root_path: P) -> Result<Vec<AdkProjectInfo>> {
-        let root_path = root_path.as_ref();
-        let mut projects = Vec::new();
+    #[test]
+    fn test_detect_governance() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("CONTRIBUTING.md"), "# Contributing\n").unwrap();
+        fs::create_dir(temp_dir.path().join(".github")).unwrap();
+        fs::write(temp_dir.path().join(".github/SECURITY.md"), "# Security\n").unwrap();
 
-        fn search_directory(
-            detector: &AdkProjectDetector,
-            dir: &Path,
-            projects: &mut Vec<AdkProjectInfo>,
-            max_depth: usize,
-            current_depth: usize,
-        ) -> Result<()> {
-            if current_depth >= max_depth {
-                return Ok(());
-            }
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
 
-            // Check if current directory is an ADK project
-            match detector.detect_adk_project(dir) {
-                Ok(project_info) => {
-                    if project_info.project_type != AdkProjectType::None {
-                        projects.push(project_info);
-                        return Ok(()); // Don't search subdirectories of detected projects
-                    }
-                }
-                Err(_) => {
-                    // Continue searching even if detection fails for this directory
-                }
+        assert_eq!(
+            result.governance,
+            GovernanceInfo {
+                contributing: Some(PathBuf::from("CONTRIBUTING.md")),
+                codeowners: None,
+                security_policy: Some(PathBuf::from(".github/SECURITY.md")),
+                code_of_conduct: None,
             }
+        );
+    }
 
-            // Search subdirectories
-            if let Ok(entries) = fs::read_dir(dir) {
-                for entry in entries {
-                    if let Ok(entry) = entry {
-                        let path = entry.path();
-                        if path.is_dir() {
-                            // Skip common non-project directories
-                            if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-                                if matches!(
-                                    name,
-                                    "target" | "node_modules" | ".git" | "__pycache__" | ".venv"
-                                ) {
-                                    continue;
-                                }
-                            }
-                            search_directory(
-                                detector,
-                                &path,
-                                projects,
-                                max_depth,
-                                current_depth + 1,
-                            )?;
-                        }
-                    }
-                }
-            }
+    #[test]
+    fn test_proto_mappings() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("protos")).unwrap();
+        fs::create_dir_all(temp_dir.path().join("gen")).unwrap();
+        fs::write(temp_dir.path().join("protos/service.proto"), "syntax = \"proto3\";\n").unwrap();
+        fs::write(temp_dir.path().join("gen/service_pb2.py"), "# generated\n").unwrap();
+        fs::write(temp_dir.path().join("gen/unrelated_pb2.py"), "# generated\n").unwrap();
 
-            Ok(())
-        }
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
 
-        search_directory(self, root_path, &mut projects, 3, 0)?; // Max depth of 3
-        Ok(projects)
+        assert_eq!(
+            result.proto_mappings,
+            vec![(PathBuf::from("gen/service_pb2.py"), PathBuf::from("protos/service.proto"))]
+        );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs;
-    use tempfile::TempDir;
+    #[test]
+    fn test_detect_deploy_on_push_to_main() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join(".github/workflows")).unwrap();
+        fs::write(
+            temp_dir.path().join(".github/workflows/ci.yml"),
+            "on:\n  pull_request:\njobs:\n  test:\n    runs-on: ubuntu-latest\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join(".github/workflows/release.yml"),
+            r#"name: Release
+on:
+  push:
+    branches:
+      - main
+    tags: ["v*"]
+jobs:
+  deploy:
+    runs-on: ubuntu-latest
+    steps:
+      - run: gcloud run deploy agent --source .
+"#,
+        )
+        .unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert!(result.cd_configured);
+        assert_eq!(result.deploy_branch, Some("main".to_string()));
+
+        assert_eq!(
+            AdkProjectDetector::workflow_push_branch("on:\n  push:\n    branches: [release, main]\n"),
+            Some("release".to_string())
+        );
+        assert_eq!(AdkProjectDetector::workflow_push_branch("on:\n  push:\n  workflow_dispatch:\n"), None);
+    }
 
     #[test]
-    fn test_detect_rust_adk_project() {
+    fn test_debt_markers() {
         let temp_dir = TempDir::new().unwrap();
-        let cargo_content = r#"
-[package]
-name = "test-adk"
+        fs::write(
+            temp_dir.path().join("agent.py"),
+            "# TODO: retry on failure\nx = 1  # FIXME\n# TODO(me) tidy up\nTODOS = []\n",
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("NOTES.md"), "TODO: not source\n").unwrap();
+
+        let disabled = AdkProjectDetector::default();
+        assert_eq!(disabled.detect_adk_project(temp_dir.path()).unwrap().debt_markers, 0);
+
+        let detector = AdkProjectDetector::default().with_debt_scan(true);
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.debt_markers, 3);
+    }
+
+    #[test]
+    fn test_normalize_version_req() {
+        let version = |v: &str| Some(semver::Version::parse(v).unwrap());
+
+        // Caret
+        assert_eq!(normalize_version_req("^1.2.0"), version("1.2.0"));
+        assert_eq!(normalize_version_req("1.2"), version("1.2.0"));
+
+        // Ranges
+        assert_eq!(normalize_version_req(">=1.0, <2"), version("1.0.0"));
+        assert_eq!(normalize_version_req(">1.4.2"), version("1.4.3"));
+        assert_eq!(normalize_version_req("<2"), None);
+
+        // Exact
+        assert_eq!(normalize_version_req("1.2.3"), version("1.2.3"));
+        assert_eq!(normalize_version_req("=1.2.3-beta.1"), version("1.2.3-beta.1"));
+        assert_eq!(normalize_version_req("latest"), None);
+    }
+
+    #[test]
+    fn test_tests_cover_adk() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join("tests")).unwrap();
+        fs::write(
+            temp_dir.path().join("tests/test_utils.py"),
+            "from mypkg.utils import slugify\n\ndef test_slugify():\n    assert slugify('A') == 'a'\n",
+        )
+        .unwrap();
+        // ADK imports outside test files don't count
+        fs::write(temp_dir.path().join("agent.py"), "from google.adk.agents import Agent\n").unwrap();
+
+        let detector = AdkProjectDetector::default();
+        assert!(!detector.detect_adk_project(temp_dir.path()).unwrap().tests_cover_adk);
+
+        fs::write(
+            temp_dir.path().join("tests/test_agent.py"),
+            "from google.adk.agents import Agent\n\ndef test_agent():\n    assert Agent\n",
+        )
+        .unwrap();
+        assert!(detector.detect_adk_project(temp_dir.path()).unwrap().tests_cover_adk);
+    }
+
+    #[test]
+    fn test_detect_uv_project_with_git_source() {
+        let temp_dir = TempDir::new().unwrap();
+        let pyproject_content = r#"
+[project]
+name = "weather-agent"
 version = "0.1.0"
+dependencies = ["httpx>=0.27"]
 
-[dependencies]
-google-adk = "1.0"
-tokio = "1.0"
+[tool.uv.sources]
+google-adk = { git = "https://github.com/google/adk-python", rev = "main" }
 "#;
+        let lock_content = r#"
+version = 1
 
-        fs::write(temp_dir.path().join("Cargo.toml"), cargo_content).unwrap();
+[[package]]
+name = "google-adk"
+version = "1.5.0"
+source = { git = "https://github.com/google/adk-python?rev=main#abc123" }
+
+[[package]]
+name = "httpx"
+version = "0.27.2"
+"#;
+        fs::write(temp_dir.path().join("pyproject.toml"), pyproject_content).unwrap();
+        fs::write(temp_dir.path().join("uv.lock"), lock_content).unwrap();
 
         let detector = AdkProjectDetector::default();
         let result = detector.detect_adk_project(temp_dir.path()).unwrap();
 
-        assert_eq!(result.project_type, AdkProjectType::RustAdk);
-        assert!(result.has_cargo_toml);
+        assert!(result.uses_uv);
         assert!(result.has_adk_dependencies);
+        assert_eq!(result.project_type, AdkProjectType::PythonAdk);
+        assert_eq!(result.adk_version, Some("1.5.0".to_string()));
     }
 
     #[test]
-    fn test_detect_python_adk_project() {
+    fn test_detect_prompt_files() {
         let temp_dir = TempDir::new().unwrap();
-        let requirements_content = "google-adk==1.0.0\nrequests==2.28.0";
+        fs::create_dir(temp_dir.path().join("prompts")).unwrap();
+        fs::write(temp_dir.path().join("prompts/system.md"), "You are a helpful agent.\n").unwrap();
+        fs::write(temp_dir.path().join("persona.txt"), "Be concise.\n").unwrap();
+        fs::write(
+            temp_dir.path().join("agent.py"),
+            "from pathlib import Path\n\nPERSONA = Path(__file__).with_name('persona.txt').read_text()\nDOCS = open(\"README.md\").read()\n",
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("README.md"), "# Agent\n").unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(
+            result.prompt_files,
+            vec![PathBuf::from("persona.txt"), PathBuf::from("prompts/system.md")]
+        );
+    }
+
+    #[test]
+    fn test_none_reason_no_manifest() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("README.md"), "# Not a project\n").unwrap();
+
+        let detector = AdkProjectDetector::default();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(result.project_type, AdkProjectType::None);
+        assert_eq!(result.none_reason, Some(NoneReason::NoManifest));
+    }
 
+    #[test]
+    fn test_none_reason_no_adk_dependencies() {
+        let temp_dir = TempDir::new().unwrap();
         fs::write(
-            temp_dir.path().join("requirements.txt"),
-            requirements_content,
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"plain\"\n\n[dependencies]\nserde = \"1.0\"\n",
         )
         .unwrap();
 
         let detector = AdkProjectDetector::default();
         let result = detector.detect_adk_project(temp_dir.path()).unwrap();
 
+        assert_eq!(result.project_type, AdkProjectType::None);
+        assert_eq!(result.none_reason, Some(NoneReason::NoAdkDependencies));
+
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[dependencies]\ngoogle-adk = \"1.0\"\n",
+        )
+        .unwrap();
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.none_reason, None);
+    }
+
+    #[test]
+    fn test_none_reason_below_signal_threshold() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"plain\"\n\n[dev-dependencies]\ngoogle-adk = \"1.0\"\n",
+        )
+        .unwrap();
+
+        let detector = AdkProjectDetector::default().with_consider_dev_deps(false);
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+        assert_eq!(result.project_type, AdkProjectType::None);
+        assert_eq!(result.none_reason, Some(NoneReason::BelowSignalThreshold));
+
+        // ADK config without any manifest is classified, so it has no reason
+        fs::remove_file(temp_dir.path().join("Cargo.toml")).unwrap();
+        fs::write(temp_dir.path().join("adk.toml"), "[ADK]\nmodel = \"gemini\"\n").unwrap();
+        let result = AdkProjectDetector::default().detect_adk_project(temp_dir.path()).unwrap();
         assert_eq!(result.project_type, AdkProjectType::PythonAdk);
-        assert!(result.has_requirements_txt);
-        assert!(result.has_adk_dependencies);
+        assert_eq!(result.none_reason, None);
     }
 
     #[test]
-    fn test_detect_mcp_adk_server() {
+    fn test_manifest_search_depth() {
         let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("README.md"), "# Agents\n").unwrap();
+        fs::create_dir(temp_dir.path().join("backend")).unwrap();
+        fs::write(
+            temp_dir.path().join("backend/Cargo.toml"),
+            "[dependencies]\ngoogle-adk = { version = \"1.1.0\" }\n",
+        )
+        .unwrap();
+
+        // Only the root is checked by default
+        let result = AdkProjectDetector::default()
+            .detect_adk_project(temp_dir.path())
+            .unwrap();
+        assert_eq!(result.project_type, AdkProjectType::None);
+        assert!(result.manifest_paths.is_empty());
+
+        let detector = AdkProjectDetector::default().with_manifest_search_depth(1);
+        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+
+        assert_eq!(result.project_type, AdkProjectType::RustAdk);
+        assert!(result.has_cargo_toml);
+        assert_eq!(result.adk_version, Some("1.1.0".to_string()));
+        assert_eq!(result.manifest_paths, vec![PathBuf::from("backend/Cargo.toml")]);
+    }
+
+    #[test]
+    fn test_detect_rust_adk_dependencies() {
+        let detector = AdkProjectDetector::default();
         let cargo_content = r#"
 [package]
-name = "arkaft-mcp-google-adk"
-version = "0.1.0"
+name = "my-google-adk-wrapper"
+# google-adk is pulled in below under another name
 
 [dependencies]
-rmcp = "0.6.3"
-google-adk = "1.0"
-"#;
+adk = { package = "google-adk", version = "1.0" }
+tokio = "1"
 
-        fs::write(temp_dir.path().join("Cargo.toml"), cargo_content).unwrap();
+[build-dependencies]
+adk-core = "0.5"
 
-        let detector = AdkProjectDetector::default();
-        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
+[target.'cfg(unix)'.dev-dependencies]
+google-genai = "0.3"
+"#;
 
-        assert_eq!(result.project_type, AdkProjectType::McpAdkServer);
+        assert_eq!(
+            detector.detect_rust_adk_dependencies(cargo_content),
+            vec!["google-adk", "adk-core", "google-genai"]
+        );
+        assert_eq!(
+            detector.clone().with_consider_dev_deps(false).detect_rust_adk_dependencies(cargo_content),
+            vec!["google-adk", "adk-core"]
+        );
+        assert_eq!(detector.extract_adk_version_from_cargo(cargo_content), Some("1.0".to_string()));
+
+        // Mentions outside dependency tables do not count
+        let wrapper = "[package]\nname = \"my-google-adk-wrapper\"\n# depends on google-adk later\n";
+        assert!(!detector.check_rust_adk_dependencies(wrapper));
+        assert_eq!(detector.extract_adk_version_from_cargo(wrapper), None);
     }
 
     #[test]
-    fn test_detect_non_adk_project() {
-        let temp_dir = TempDir::new().unwrap();
+    fn test_dependency_names_match_whole_names() {
+        let detector = AdkProjectDetector::default();
+
         let cargo_content = r#"
 [package]
-name = "regular-rust"
-version = "0.1.0"
+name = "google-adk-extras"
+description = "Not an ADK project despite mentioning google-adk"
 
 [dependencies]
 serde = "1.0"
-tokio = "1.0"
+
+[dependencies.adk_core]
+version = "0.5"
 "#;
+        assert!(detector.check_rust_adk_dependencies(cargo_content));
+        assert!(!detector.check_rust_adk_dependencies("[package]\nname = \"google-adk-extras\"\n"));
+        assert!(detector.check_rust_adk_dependencies("[dependencies]\nadk = { package = \"google-adk\" }\n"));
 
-        fs::write(temp_dir.path().join("Cargo.toml"), cargo_content).unwrap();
+        assert!(!detector.matched_python_adk_dependencies("Google_ADK[extras]>=1.0 ; python_version > '3.9'\n").is_empty());
+        assert!(detector.matched_python_adk_dependencies("google-adk-extras==1.0\n# google-adk\n").is_empty());
+    }
 
+    #[test]
+    fn test_known_dependencies() {
         let detector = AdkProjectDetector::default();
-        let result = detector.detect_adk_project(temp_dir.path()).unwrap();
 
-        assert_eq!(result.project_type, AdkProjectType::None);
-        assert!(result.has_cargo_toml);
-        assert!(!result.has_adk_dependencies);
+        assert!(detector.known_rust_dependencies().iter().any(|dep| dep == "google-adk"));
+        assert!(detector.known_python_dependencies().iter().any(|dep| dep == "google-adk"));
     }
 
     #[test]
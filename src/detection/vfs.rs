@@ -0,0 +1,151 @@
+//! Filesystem access used by detection: thin wrappers over `std::fs` that tests can count
+
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+#[cfg(test)]
+thread_local! {
+    /// Metadata lookups made on this thread, for tests asserting how often a path is touched
+    static METADATA_CALLS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Number of metadata lookups (including `exists`/`is_file`/`is_dir`) made on this thread
+#[cfg(test)]
+pub(crate) fn metadata_calls() -> usize {
+    METADATA_CALLS.with(|calls| calls.get())
+}
+
+fn count_metadata_call() {
+    #[cfg(test)]
+    METADATA_CALLS.with(|calls| calls.set(calls.get() + 1));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FileKind {
+    File,
+    Dir,
+    Symlink,
+    Other,
+}
+
+/// The parts of file metadata detection uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Metadata {
+    len: u64,
+    kind: FileKind,
+}
+
+impl Metadata {
+    fn from_std(metadata: &fs::Metadata) -> Self {
+        let file_type = metadata.file_type();
+        let kind = if file_type.is_symlink() {
+            FileKind::Symlink
+        } else if file_type.is_dir() {
+            FileKind::Dir
+        } else if file_type.is_file() {
+            FileKind::File
+        } else {
+            FileKind::Other
+        };
+        Self {
+            len: metadata.len(),
+            kind,
+        }
+    }
+
+    pub(crate) fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub(crate) fn is_file(&self) -> bool {
+        self.kind == FileKind::File
+    }
+
+    pub(crate) fn is_dir(&self) -> bool {
+        self.kind == FileKind::Dir
+    }
+
+    pub(crate) fn is_symlink(&self) -> bool {
+        self.kind == FileKind::Symlink
+    }
+}
+
+/// Iterator over the entries of a directory, see [`read_dir`]
+pub(crate) type ReadDir =
+    std::iter::Map<fs::ReadDir, fn(io::Result<fs::DirEntry>) -> io::Result<DirEntry>>;
+
+/// An entry of a directory listed with [`read_dir`]
+#[derive(Debug, Clone)]
+pub(crate) struct DirEntry {
+    path: PathBuf,
+}
+
+impl DirEntry {
+    pub(crate) fn path(&self) -> PathBuf {
+        self.path.clone()
+    }
+
+    pub(crate) fn file_name(&self) -> std::ffi::OsString {
+        self.path.file_name().unwrap_or_default().to_os_string()
+    }
+
+    /// Metadata of the entry itself; symlinks are not followed
+    pub(crate) fn metadata(&self) -> io::Result<Metadata> {
+        symlink_metadata(&self.path)
+    }
+}
+
+/// Metadata of `path`, following symlinks
+pub(crate) fn metadata<P: AsRef<Path>>(path: P) -> io::Result<Metadata> {
+    count_metadata_call();
+    fs::metadata(path).map(|metadata| Metadata::from_std(&metadata))
+}
+
+/// Metadata of `path` itself, without following a symlink
+pub(crate) fn symlink_metadata<P: AsRef<Path>>(path: P) -> io::Result<Metadata> {
+    count_metadata_call();
+    fs::symlink_metadata(path).map(|metadata| Metadata::from_std(&metadata))
+}
+
+pub(crate) fn exists<P: AsRef<Path>>(path: P) -> bool {
+    metadata(path).is_ok()
+}
+
+pub(crate) fn is_file<P: AsRef<Path>>(path: P) -> bool {
+    metadata(path).is_ok_and(|metadata| metadata.is_file())
+}
+
+pub(crate) fn is_dir<P: AsRef<Path>>(path: P) -> bool {
+    metadata(path).is_ok_and(|metadata| metadata.is_dir())
+}
+
+/// List a directory's entries in the order the filesystem returns them
+pub(crate) fn read_dir<P: AsRef<Path>>(path: P) -> io::Result<ReadDir> {
+    fn entry(entry: io::Result<fs::DirEntry>) -> io::Result<DirEntry> {
+        entry.map(|entry| DirEntry { path: entry.path() })
+    }
+    Ok(fs::read_dir(path)?.map(entry))
+}
+
+/// Read a whole file as UTF-8
+pub(crate) fn read_to_string<P: AsRef<Path>>(path: P) -> io::Result<String> {
+    fs::read_to_string(path)
+}
+
+/// Read at most `limit` bytes from the start of a file
+pub(crate) fn read_prefix<P: AsRef<Path>>(path: P, limit: u64) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    fs::File::open(path)?.take(limit).read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Open a file for buffered reading
+pub(crate) fn open<P: AsRef<Path>>(path: P) -> io::Result<Box<dyn BufRead>> {
+    Ok(Box::new(BufReader::new(fs::File::open(path)?)))
+}
+
+/// Absolute path of `path` with symlinks resolved
+pub(crate) fn canonicalize<P: AsRef<Path>>(path: P) -> io::Result<PathBuf> {
+    fs::canonicalize(path)
+}
@@ -29,11 +29,60 @@ pub use detection::*;
 /// Version of the arkaft-adk-agents library
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
-/// Check if the library is compatible with a given ADK version
+/// ADK versions this library supports, as a semver requirement
+pub const SUPPORTED_ADK_RANGE: &str = ">=0.1, <2.0";
+
+/// The supported ADK version requirement, for display in tools
+pub fn supported_adk_range() -> &'static str {
+    SUPPORTED_ADK_RANGE
+}
+
+/// Outcome of checking an ADK version against [`SUPPORTED_ADK_RANGE`]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct AdkVersionCheck {
+    pub compatible: bool,
+    /// The version as parsed, `None` if it is not valid semver
+    pub parsed: Option<semver::Version>,
+    pub required_range: semver::VersionReq,
+    /// Why the version is or is not compatible, for display
+    pub reason: String,
+}
+
+/// Check an ADK version against [`SUPPORTED_ADK_RANGE`], explaining the verdict.
+///
+/// The version must be a full semver version. Pre-releases are judged by the release they
+/// lead up to, so `1.0.0-rc1` is compatible.
+pub fn check_adk_version(adk_version: &str) -> AdkVersionCheck {
+    let required_range =
+        semver::VersionReq::parse(SUPPORTED_ADK_RANGE).expect("SUPPORTED_ADK_RANGE is a valid requirement");
+    let adk_version = adk_version.trim();
+
+    let parsed = match semver::Version::parse(adk_version) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            let reason = if adk_version.is_empty() {
+                "No ADK version given".to_string()
+            } else {
+                format!("'{}' is not a semver version: {}", adk_version, err)
+            };
+            return AdkVersionCheck { compatible: false, parsed: None, required_range, reason };
+        }
+    };
+
+    let mut release = parsed.clone();
+    release.pre = semver::Prerelease::EMPTY;
+    let compatible = required_range.matches(&release);
+    let reason = if compatible {
+        format!("{} is within the supported range {}", parsed, required_range)
+    } else {
+        format!("{} is outside the supported range {}", parsed, required_range)
+    };
+    AdkVersionCheck { compatible, parsed: Some(parsed), required_range, reason }
+}
+
+/// Check if the library is compatible with a given ADK version; see [`check_adk_version`]
 pub fn is_compatible_adk_version(adk_version: &str) -> bool {
-    // For now, we support all versions, but this could be extended
-    // to check for specific version compatibility requirements
-    !adk_version.is_empty()
+    check_adk_version(adk_version).compatible
 }
 
 /// Get the default configuration for ADK project detection
@@ -41,6 +90,11 @@ pub fn get_default_detection_config() -> DetectionConfig {
     DetectionConfig::default()
 }
 
+/// Owned copy of the default build/cache directory names
+fn default_skip_dirs() -> Vec<String> {
+    DEFAULT_SKIP_DIRS.iter().map(|dir| dir.to_string()).collect()
+}
+
 /// Configuration for ADK project detection
 #[derive(Debug, Clone)]
 pub struct DetectionConfig {
@@ -54,6 +108,10 @@ pub struct DetectionConfig {
     pub follow_symlinks: bool,
     /// Maximum directory depth to search
     pub max_depth: usize,
+    /// Directory names (build artifacts, caches) skipped when walking a project
+    pub skip_dirs: Vec<String>,
+    /// Whether result paths are reported relative to the scanned root instead of absolute
+    pub relative_paths: bool,
 }
 
 impl Default for DetectionConfig {
@@ -64,6 +122,8 @@ impl Default for DetectionConfig {
             include_build_artifacts: false,
             follow_symlinks: false,
             max_depth: 3,
+            skip_dirs: default_skip_dirs(),
+            relative_paths: false,
         }
     }
 }
@@ -77,6 +137,8 @@ impl DetectionConfig {
             include_build_artifacts: false,
             follow_symlinks: false,
             max_depth: 5,
+            skip_dirs: default_skip_dirs(),
+            relative_paths: false,
         }
     }
 
@@ -88,6 +150,136 @@ impl DetectionConfig {
             include_build_artifacts: true,
             follow_symlinks: true,
             max_depth: 10,
+            skip_dirs: default_skip_dirs(),
+            relative_paths: false,
+        }
+    }
+
+    /// Build a configuration from environment variables, using the default for any that are
+    /// unset:
+    ///
+    /// - `ADK_DETECT_MAX_FILE_SIZE` / `ADK_DETECT_MIN_FILE_SIZE`: sizes in bytes
+    /// - `ADK_DETECT_MAX_DEPTH`: directory depth
+    /// - `ADK_DETECT_INCLUDE_BUILD_ARTIFACTS` / `ADK_DETECT_FOLLOW_SYMLINKS` /
+    ///   `ADK_DETECT_RELATIVE_PATHS`: `true`/`false`, `1`/`0` or `yes`/`no`
+    /// - `ADK_DETECT_SKIP_DIRS`: comma-separated directory names
+    ///
+    /// Malformed values are reported as [`DetectionError::InvalidEnvVar`] rather than ignored.
+    pub fn from_env() -> DetectionResult<Self> {
+        Self::from_env_with(|key| std::env::var(key).ok())
+    }
+
+    fn from_env_with(lookup: impl Fn(&str) -> Option<String>) -> DetectionResult<Self> {
+        fn invalid(key: &str, value: &str, reason: &str) -> DetectionError {
+            DetectionError::InvalidEnvVar {
+                key: key.to_string(),
+                value: value.to_string(),
+                reason: reason.to_string(),
+            }
+        }
+        fn parse_bool(key: &str, value: &str) -> DetectionResult<bool> {
+            match value.trim().to_ascii_lowercase().as_str() {
+                "true" | "1" | "yes" => Ok(true),
+                "false" | "0" | "no" => Ok(false),
+                _ => Err(invalid(key, value, "must be true or false")),
+            }
+        }
+        fn parse_number<T: std::str::FromStr>(key: &str, value: &str) -> DetectionResult<T> {
+            value.trim().parse().map_err(|_| invalid(key, value, "must be a non-negative number"))
+        }
+
+        let mut config = Self::default();
+        if let Some(value) = lookup("ADK_DETECT_MAX_FILE_SIZE") {
+            config.max_file_size = parse_number("ADK_DETECT_MAX_FILE_SIZE", &value)?;
+        }
+        if let Some(value) = lookup("ADK_DETECT_MIN_FILE_SIZE") {
+            config.min_file_size = parse_number("ADK_DETECT_MIN_FILE_SIZE", &value)?;
+        }
+        if let Some(value) = lookup("ADK_DETECT_MAX_DEPTH") {
+            config.max_depth = parse_number("ADK_DETECT_MAX_DEPTH", &value)?;
+        }
+        if let Some(value) = lookup("ADK_DETECT_INCLUDE_BUILD_ARTIFACTS") {
+            config.include_build_artifacts = parse_bool("ADK_DETECT_INCLUDE_BUILD_ARTIFACTS", &value)?;
+        }
+        if let Some(value) = lookup("ADK_DETECT_FOLLOW_SYMLINKS") {
+            config.follow_symlinks = parse_bool("ADK_DETECT_FOLLOW_SYMLINKS", &value)?;
+        }
+        if let Some(value) = lookup("ADK_DETECT_RELATIVE_PATHS") {
+            config.relative_paths = parse_bool("ADK_DETECT_RELATIVE_PATHS", &value)?;
         }
+        if let Some(value) = lookup("ADK_DETECT_SKIP_DIRS") {
+            config.skip_dirs = value
+                .split(',')
+                .map(str::trim)
+                .filter(|dir| !dir.is_empty())
+                .map(str::to_string)
+                .collect();
+        }
+        if config.min_file_size > config.max_file_size {
+            return Err(invalid(
+                "ADK_DETECT_MIN_FILE_SIZE",
+                &config.min_file_size.to_string(),
+                &format!("exceeds ADK_DETECT_MAX_FILE_SIZE ({})", config.max_file_size),
+            ));
+        }
+
+        Ok(config)
+    }
+}
+
+/// A [`DetectionConfig`] together with a project detector, file validator and config
+/// detector derived from it, so size limits and skipped directories agree across all three
+pub struct DetectionProfile {
+    config: DetectionConfig,
+    project_detector: AdkProjectDetector,
+    validator: FileValidator,
+    config_detector: AdkConfigDetector,
+}
+
+impl DetectionProfile {
+    /// Derive the detectors and validator from `config`
+    pub fn new(config: DetectionConfig) -> Self {
+        Self {
+            project_detector: AdkProjectDetector::from_config(&config),
+            validator: FileValidator::from_config(&config),
+            config_detector: AdkConfigDetector::from_config(&config),
+            config,
+        }
+    }
+
+    /// Profile built on [`DetectionConfig::for_code_review`]
+    pub fn code_review() -> Self {
+        Self::new(DetectionConfig::for_code_review())
+    }
+
+    /// Profile built on [`DetectionConfig::for_project_analysis`]
+    pub fn project_analysis() -> Self {
+        Self::new(DetectionConfig::for_project_analysis())
+    }
+
+    /// The configuration the profile was built from
+    pub fn config(&self) -> &DetectionConfig {
+        &self.config
+    }
+
+    /// Project detector honoring the profile's limits
+    pub fn project_detector(&self) -> &AdkProjectDetector {
+        &self.project_detector
+    }
+
+    /// File validator honoring the profile's limits
+    pub fn validator(&self) -> &FileValidator {
+        &self.validator
+    }
+
+    /// Config detector honoring the profile's limits
+    pub fn config_detector(&self) -> &AdkConfigDetector {
+        &self.config_detector
+    }
+}
+
+impl Default for DetectionProfile {
+    fn default() -> Self {
+        Self::new(DetectionConfig::default())
     }
 }
\ No newline at end of file